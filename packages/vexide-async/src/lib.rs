@@ -10,6 +10,7 @@ extern crate alloc;
 mod executor;
 mod reactor;
 
+pub mod state_machine;
 pub mod task;
 pub mod time;
 
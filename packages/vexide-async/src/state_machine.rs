@@ -0,0 +1,227 @@
+//! Declarative state machines for sequencing asynchronous routines.
+//!
+//! Autonomous routines are often naturally described as a series of states (such as
+//! "drive to goal", "score", "back up"), where the result of each state determines which
+//! state runs next. This module provides a small runner for these kinds of routines.
+//!
+//! # Defining States
+//!
+//! States are defined by implementing the [`State`] trait, typically on an enum. Each state
+//! runs asynchronously with mutable access to a shared *context* (usually a struct holding
+//! the robot's devices), and returns a [`Transition`] describing what should happen next.
+//!
+//! A state may also declare a [`timeout`](State::timeout). If the state does not complete
+//! within this duration, it is cancelled and [`State::on_timeout`] is called instead to pick
+//! the next transition.
+//!
+//! # Running
+//!
+//! A [`StateMachine`] is created from an initial state and run to completion using
+//! [`StateMachine::run`]. An overall deadline can be set for the whole machine using
+//! [`StateMachine::with_deadline`] or [`StateMachine::with_timeout`]. If the deadline is
+//! reached, the current state is cancelled and [`Outcome::DeadlineExceeded`] is returned.
+//!
+//! # Cancellation
+//!
+//! Like any other future, the future returned by [`StateMachine::run`] can be cancelled at any
+//! point by dropping it. This is how the competition runtime aborts an autonomous routine when
+//! the competition mode changes, so a state machine used as an autonomous routine will stop
+//! as soon as the autonomous period ends without any extra handling.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use core::time::Duration;
+//!
+//! use vexide::prelude::*;
+//! use vexide::async_runtime::state_machine::{State, StateMachine, Transition};
+//!
+//! struct Robot {
+//!     intake: Motor,
+//! }
+//!
+//! enum Auton {
+//!     Intake,
+//!     Outtake,
+//! }
+//!
+//! impl State<Robot> for Auton {
+//!     async fn run(&mut self, robot: &mut Robot) -> Transition<Self> {
+//!         match self {
+//!             Self::Intake => {
+//!                 robot.intake.set_voltage(12.0).ok();
+//!                 sleep(Duration::from_secs(2)).await;
+//!                 Transition::Next(Self::Outtake)
+//!             }
+//!             Self::Outtake => {
+//!                 robot.intake.set_voltage(-12.0).ok();
+//!                 sleep(Duration::from_secs(1)).await;
+//!                 robot.intake.set_voltage(0.0).ok();
+//!                 Transition::Done
+//!             }
+//!         }
+//!     }
+//!
+//!     fn timeout(&self) -> Option<Duration> {
+//!         Some(Duration::from_secs(3))
+//!     }
+//! }
+//!
+//! #[vexide::main]
+//! async fn main(peripherals: Peripherals) {
+//!     let mut robot = Robot {
+//!         intake: Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward),
+//!     };
+//!
+//!     StateMachine::new(Auton::Intake)
+//!         .with_timeout(Duration::from_secs(15))
+//!         .run(&mut robot)
+//!         .await;
+//! }
+//! ```
+
+use core::{
+    future::{poll_fn, Future},
+    pin::{pin, Pin},
+    task::Poll,
+    time::Duration,
+};
+
+use vexide_core::time::Instant;
+
+use crate::time::sleep_until;
+
+/// The action a [`StateMachine`] should take after a [`State`] finishes running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transition<S> {
+    /// Move to the given state.
+    Next(S),
+
+    /// Stop the state machine.
+    Done,
+}
+
+/// A state in a [`StateMachine`] with access to a shared context of type `C`.
+///
+/// See the [module-level documentation](self) for more information.
+pub trait State<C>: Sized {
+    /// Runs this state, returning the transition that should be taken once it completes.
+    fn run(&mut self, context: &mut C) -> impl Future<Output = Transition<Self>>;
+
+    /// Returns the maximum amount of time this state is allowed to run for.
+    ///
+    /// If the state does not complete within this duration, the future returned by
+    /// [`State::run`] is dropped and [`State::on_timeout`] is called to determine the next
+    /// transition. By default, states have no timeout.
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Returns the transition that should be taken if this state exceeds its [timeout](State::timeout).
+    ///
+    /// By default, this stops the state machine.
+    fn on_timeout(&mut self, context: &mut C) -> Transition<Self> {
+        _ = context;
+        Transition::Done
+    }
+}
+
+/// The result of running a [`StateMachine`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome<S> {
+    /// A state returned [`Transition::Done`].
+    Done,
+
+    /// The state machine's deadline was reached before it finished.
+    ///
+    /// Contains the state that was running when the deadline was reached.
+    DeadlineExceeded(S),
+}
+
+/// A runner that advances through [`State`]s until one of them finishes the routine.
+///
+/// See the [module-level documentation](self) for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateMachine<S> {
+    initial: S,
+    deadline: Option<Instant>,
+}
+
+impl<S> StateMachine<S> {
+    /// Creates a new state machine starting at the given state.
+    #[must_use]
+    pub const fn new(initial: S) -> Self {
+        Self {
+            initial,
+            deadline: None,
+        }
+    }
+
+    /// Sets an instant at which the whole state machine will be stopped, regardless of
+    /// which state is running.
+    #[must_use]
+    pub const fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Sets the maximum amount of time the whole state machine may run for, starting from
+    /// when this function is called.
+    #[must_use]
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        self.with_deadline(Instant::now() + timeout)
+    }
+
+    /// Runs the state machine until a state returns [`Transition::Done`] or the deadline
+    /// is reached.
+    pub async fn run<C>(self, context: &mut C) -> Outcome<S>
+    where
+        S: State<C>,
+    {
+        let mut state = self.initial;
+
+        loop {
+            let state_deadline = state.timeout().map(|timeout| Instant::now() + timeout);
+
+            // Whichever deadline comes first will interrupt the state.
+            let (deadline, is_state_timeout) = match (state_deadline, self.deadline) {
+                (Some(state_deadline), Some(deadline)) if deadline <= state_deadline => {
+                    (Some(deadline), false)
+                }
+                (Some(state_deadline), _) => (Some(state_deadline), true),
+                (None, deadline) => (deadline, false),
+            };
+
+            let transition = {
+                let mut run = pin!(state.run(context));
+                let mut timer = deadline.map(sleep_until);
+
+                poll_fn(|cx| {
+                    if let Poll::Ready(transition) = run.as_mut().poll(cx) {
+                        return Poll::Ready(Some(transition));
+                    }
+
+                    if let Some(timer) = timer.as_mut() {
+                        if Pin::new(timer).poll(cx).is_ready() {
+                            return Poll::Ready(None);
+                        }
+                    }
+
+                    Poll::Pending
+                })
+                .await
+            };
+
+            let transition = match transition {
+                Some(transition) => transition,
+                None if is_state_timeout => state.on_timeout(context),
+                None => return Outcome::DeadlineExceeded(state),
+            };
+
+            match transition {
+                Transition::Next(next) => state = next,
+                Transition::Done => return Outcome::Done,
+            }
+        }
+    }
+}
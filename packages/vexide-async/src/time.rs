@@ -7,10 +7,16 @@
 //!
 //! * [`sleep`] and [`sleep_until`] provide ways to yield control away from a future
 //!   for or until a specific instant in time.
+//!
+//! * [`retry_with_backoff`] retries a fallible operation, sleeping for exponentially
+//!   increasing durations between attempts.
+//!
+//! * [`timeout`] and [`timeout_at`] limit how long a future may take to complete.
 
 use core::{
-    future::Future,
-    pin::Pin,
+    fmt,
+    future::{poll_fn, Future},
+    pin::{pin, Pin},
     task::{Context, Poll},
     time::Duration,
 };
@@ -47,3 +53,126 @@ pub fn sleep(duration: Duration) -> Sleep {
 pub const fn sleep_until(deadline: Instant) -> Sleep {
     Sleep(deadline)
 }
+
+/// Retries a fallible asynchronous operation with exponential backoff.
+///
+/// `factory` is called to create a new future for each attempt. If the future resolves to
+/// `Ok`, that value is returned immediately. If it resolves to `Err`, this function sleeps
+/// before calling `factory` again, up to a total of `max_attempts` attempts.
+///
+/// # Backoff Schedule
+///
+/// The delay before each retry doubles every attempt, starting at `base_delay`. With a
+/// `base_delay` of 10ms, the operation is retried after waiting 10ms, 20ms, 40ms, 80ms,
+/// and so on. Delays saturate at [`Duration::MAX`] rather than overflowing. No delay
+/// occurs after the final attempt.
+///
+/// # Errors
+///
+/// If every attempt fails, the error from the final attempt is returned. Errors from
+/// earlier attempts are discarded.
+///
+/// # Panics
+///
+/// Panics if `max_attempts` is zero.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use vexide::async_runtime::time::retry_with_backoff;
+///
+/// // Try connecting up to 5 times, waiting 50ms, 100ms, 200ms, then 400ms between attempts.
+/// let result = retry_with_backoff(|| connect(), 5, Duration::from_millis(50)).await;
+/// ```
+pub async fn retry_with_backoff<T, E, F, Fut>(
+    mut factory: F,
+    max_attempts: u32,
+    base_delay: Duration,
+) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    assert!(max_attempts > 0, "max_attempts must be at least 1");
+
+    let mut attempt = 1;
+
+    loop {
+        match factory().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt >= max_attempts => return Err(err),
+            Err(_) => {
+                sleep(base_delay.saturating_mul(2u32.saturating_pow(attempt - 1))).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Error returned by [`timeout`] and [`timeout_at`] when a future does not complete in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeoutError;
+
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("future did not complete before the timeout elapsed")
+    }
+}
+
+impl core::error::Error for TimeoutError {}
+
+/// Requires a future to complete within the given duration.
+///
+/// If `future` completes before `duration` has elapsed, its output is returned. Otherwise,
+/// `future` is dropped (cancelling it) and [`TimeoutError`] is returned.
+///
+/// The timer is driven by the executor's reactor in the same way as [`sleep`], so waiting
+/// on a timeout does not busy-poll the executor.
+///
+/// # Errors
+///
+/// A [`TimeoutError`] is returned if `duration` elapses before `future` completes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use vexide::async_runtime::time::timeout;
+///
+/// // Give the routine at most 5 seconds to finish.
+/// if timeout(Duration::from_secs(5), routine()).await.is_err() {
+///     println!("Routine timed out!");
+/// }
+/// ```
+pub async fn timeout<F: Future>(duration: Duration, future: F) -> Result<F::Output, TimeoutError> {
+    timeout_at(Instant::now() + duration, future).await
+}
+
+/// Requires a future to complete before the given deadline.
+///
+/// This is the same as [`timeout`], but takes an [`Instant`] rather than a [`Duration`].
+///
+/// # Errors
+///
+/// A [`TimeoutError`] is returned if `deadline` is reached before `future` completes.
+pub async fn timeout_at<F: Future>(
+    deadline: Instant,
+    future: F,
+) -> Result<F::Output, TimeoutError> {
+    let mut future = pin!(future);
+    let mut sleep = sleep_until(deadline);
+
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+
+        if Pin::new(&mut sleep).poll(cx).is_ready() {
+            return Poll::Ready(Err(TimeoutError));
+        }
+
+        Poll::Pending
+    })
+    .await
+}
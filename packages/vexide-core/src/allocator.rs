@@ -29,3 +29,30 @@ pub unsafe fn claim(start: *mut u8, end: *mut u8) {
         ALLOCATOR.lock().claim(Span::new(start, end)).unwrap();
     }
 }
+
+/// Replaces all existing heap regions with a new region of memory.
+///
+/// Unlike [`claim`], this discards any memory that was previously claimed by the allocator,
+/// allowing the heap to be moved after it has been initialized.
+///
+/// # Safety
+///
+/// - No heap allocations may be live when this function is called. Any memory previously
+///   returned by the allocator must not be used or deallocated afterwards.
+/// - The memory between `start` and `end` must be valid for reads and writes, and
+///   memory therein (when not allocated to the user) must not be mutated while
+///   the allocator is in use.
+///
+/// # Panics
+///
+/// Panics if `start` or `end` are null, or if the region is too small to be used as heap space.
+pub unsafe fn reclaim(start: *mut u8, end: *mut u8) {
+    let mut talc = ALLOCATOR.lock();
+    *talc = Talc::new(ErrOnOom);
+
+    // SAFETY: The allocator no longer has any claimed regions, and the caller guarantees
+    // that there are no live allocations from the previous regions.
+    unsafe {
+        talc.claim(Span::new(start, end)).unwrap();
+    }
+}
@@ -2,7 +2,7 @@
 
 extern crate alloc;
 
-use alloc::boxed::Box;
+use alloc::{boxed::Box, string::String};
 use core::{
     cell::UnsafeCell,
     future::{Future, IntoFuture},
@@ -10,13 +10,17 @@ use core::{
     ops::ControlFlow,
     pin::{pin, Pin},
     task::{self, Poll},
+    time::Duration,
 };
 
 use bitflags::bitflags;
 use futures_core::Stream;
 use pin_project::pin_project;
+use snafu::{ensure, Snafu};
 use vex_sdk::vexCompetitionStatus;
 
+use crate::time::Instant;
+
 bitflags! {
     /// The status bits returned by [`vex_sdk::vexCompetitionStatus`].
     #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -68,6 +72,37 @@ pub enum CompetitionMode {
     Driver,
 }
 
+/// A competition phase that can be run manually using [`CompeteExt::run_phase`].
+///
+/// Each variant corresponds to one of the handlers on the [`Compete`] trait.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompetitionPhase {
+    /// Runs [`Compete::connected`].
+    Connected,
+
+    /// Runs [`Compete::disconnected`].
+    Disconnected,
+
+    /// Runs [`Compete::disabled`].
+    Disabled,
+
+    /// Runs [`Compete::autonomous`].
+    Autonomous,
+
+    /// Runs [`Compete::driver`].
+    Driver,
+}
+
+impl From<CompetitionMode> for CompetitionPhase {
+    fn from(mode: CompetitionMode) -> Self {
+        match mode {
+            CompetitionMode::Disabled => Self::Disabled,
+            CompetitionMode::Autonomous => Self::Autonomous,
+            CompetitionMode::Driver => Self::Driver,
+        }
+    }
+}
+
 /// Represents a type of system used to control competition state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CompetitionSystem {
@@ -181,6 +216,164 @@ pub const fn updates() -> CompetitionUpdates {
     CompetitionUpdates { last_status: None }
 }
 
+/// Controller rumble cues played by a [`CompetitionRuntime`] when the match changes phase.
+///
+/// Cues give drivers a physical signal that the robot has changed modes without having
+/// to look away from the field. They are configured using [`CompetitionRuntime::with_cues`].
+///
+/// # Available Cues
+///
+/// - A **driver cue** ([`PhaseCues::on_driver`]) is played when the robot enters driver
+///   control, such as after the autonomous period ends.
+/// - An **endgame cue** ([`PhaseCues::on_endgame`]) is played once a given amount of time
+///   has passed since driver control began. Competition control does not report when
+///   endgame starts, so this is based on the program's own timer.
+///
+/// Cues are played on a controller's vibration motor using the same patterns as
+/// `Controller::rumble`: a string of `.` (short rumble), `-` (long rumble), and ` ` (pause)
+/// characters, up to 8 characters long. The V5 Brain does not have a speaker accessible to
+/// user programs, so audible cues are not available.
+///
+/// The controller that cues are played on is given to [`CompetitionRuntime::with_cues`] as a
+/// [`CueController`]. If the controller is disconnected or busy when a cue should be played
+/// (for example, because its screen is being written to), the cue is retried until it is
+/// accepted or the phase changes again.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use vexide::{core::competition::PhaseCues, devices::controller::ControllerId, prelude::*};
+///
+/// struct Robot;
+///
+/// impl Compete for Robot {}
+///
+/// #[vexide::main]
+/// async fn main(_peripherals: Peripherals) {
+///     let cues = PhaseCues::new()
+///         .on_driver(".")
+///         .unwrap()
+///         // Driver control is 1:45 long, and endgame is the final 15 seconds.
+///         .on_endgame(Duration::from_secs(90), "- -")
+///         .unwrap();
+///
+///     Robot
+///         .compete()
+///         .with_cues(ControllerId::Primary, cues)
+///         .await;
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PhaseCues {
+    driver: Option<String>,
+    endgame: Option<(Duration, String)>,
+}
+
+impl PhaseCues {
+    /// The maximum number of characters in a rumble pattern.
+    pub const MAX_PATTERN_LENGTH: usize = 8;
+
+    /// Creates a set of cues that doesn't play anything.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            driver: None,
+            endgame: None,
+        }
+    }
+
+    /// Plays a rumble `pattern` when the robot enters driver control.
+    ///
+    /// # Errors
+    ///
+    /// - A [`CuePatternError::TooLong`] error is returned if `pattern` is longer than
+    ///   [`PhaseCues::MAX_PATTERN_LENGTH`] characters.
+    /// - A [`CuePatternError::InvalidCharacter`] error is returned if `pattern` contains a
+    ///   character other than `.`, `-`, or ` `.
+    pub fn on_driver(mut self, pattern: &str) -> Result<Self, CuePatternError> {
+        self.driver = Some(Self::validate_pattern(pattern)?);
+        Ok(self)
+    }
+
+    /// Plays a rumble `pattern` once `after` has elapsed since the robot entered driver control.
+    ///
+    /// # Errors
+    ///
+    /// - A [`CuePatternError::TooLong`] error is returned if `pattern` is longer than
+    ///   [`PhaseCues::MAX_PATTERN_LENGTH`] characters.
+    /// - A [`CuePatternError::InvalidCharacter`] error is returned if `pattern` contains a
+    ///   character other than `.`, `-`, or ` `.
+    pub fn on_endgame(mut self, after: Duration, pattern: &str) -> Result<Self, CuePatternError> {
+        self.endgame = Some((after, Self::validate_pattern(pattern)?));
+        Ok(self)
+    }
+
+    fn validate_pattern(pattern: &str) -> Result<String, CuePatternError> {
+        ensure!(
+            pattern.chars().count() <= Self::MAX_PATTERN_LENGTH,
+            TooLongSnafu
+        );
+        if let Some(character) = pattern.chars().find(|c| !matches!(c, '.' | '-' | ' ')) {
+            return InvalidCharacterSnafu { character }.fail();
+        }
+
+        Ok(String::from(pattern))
+    }
+
+    /// Attempts to play the given cue on `controller`, returning `true` if the pattern was
+    /// accepted.
+    fn play(&self, cue: PhaseCue, controller: &mut dyn CueController) -> bool {
+        let pattern = match cue {
+            PhaseCue::Driver => self.driver.as_ref(),
+            PhaseCue::Endgame => self.endgame.as_ref().map(|(_, pattern)| pattern),
+        };
+
+        match pattern {
+            Some(pattern) => controller.try_play_cue(pattern),
+            None => true,
+        }
+    }
+}
+
+/// Errors that can occur when configuring a rumble pattern for [`PhaseCues`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Snafu)]
+pub enum CuePatternError {
+    /// The pattern is longer than [`PhaseCues::MAX_PATTERN_LENGTH`] characters.
+    #[snafu(display(
+        "The rumble pattern is longer than {} characters.",
+        PhaseCues::MAX_PATTERN_LENGTH
+    ))]
+    TooLong,
+
+    /// The pattern contains a character other than `.`, `-`, or ` `.
+    #[snafu(display("The rumble pattern contains an invalid character: {character:?}"))]
+    InvalidCharacter {
+        /// The invalid character.
+        character: char,
+    },
+}
+
+/// A controller that [`PhaseCues`] can be played on.
+///
+/// This is implemented by `ControllerId` in `vexide::devices::controller`, which plays cues
+/// through the controller rumble API.
+pub trait CueController {
+    /// Attempts to start playing a rumble `pattern` on the controller, returning `true` if the
+    /// pattern was accepted.
+    ///
+    /// If this returns `false`, the cue is retried the next time the [`CompetitionRuntime`] is
+    /// polled.
+    fn try_play_cue(&mut self, pattern: &str) -> bool;
+}
+
+/// A cue from [`PhaseCues`] that is waiting to be played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PhaseCue {
+    Driver,
+    Endgame,
+}
+
 /// A future which delegates to different futures depending on the current competition mode.
 /// I.e., a tiny async runtime specifically for writing competition programs.
 #[pin_project]
@@ -241,6 +434,19 @@ pub struct CompetitionRuntime<
     ///         during task creation.
     shared: UnsafeCell<Shared>,
 
+    /// Rumble cues to play on phase changes.
+    cues: PhaseCues,
+
+    /// The controller to play cues on, or [`None`] if cues are disabled.
+    cue_controller: Option<Box<dyn CueController>>,
+
+    /// A cue that should be played, but hasn't been accepted by the controller yet.
+    pending_cue: Option<PhaseCue>,
+
+    /// When driver control began, or [`None`] if the robot is not in driver control or the
+    /// endgame cue has already been played.
+    driver_start: Option<Instant>,
+
     /// Keep `self.shared` in place while `self.task` references it.
     _pin: PhantomPinned,
 }
@@ -351,12 +557,66 @@ where
                     Some((this.mk_driver)(shared))
                 }
             };
+
+            if *this.phase == CompetitionRuntimePhase::Mode(CompetitionMode::Driver) {
+                *this.driver_start = Some(Instant::now());
+                *this.pending_cue = Some(PhaseCue::Driver);
+            } else {
+                *this.driver_start = None;
+                *this.pending_cue = None;
+            }
+        }
+
+        if let (Some(driver_start), Some((after, _))) = (*this.driver_start, &this.cues.endgame) {
+            if driver_start.elapsed() >= *after {
+                *this.driver_start = None;
+                *this.pending_cue = Some(PhaseCue::Endgame);
+            }
+        }
+
+        if let (Some(cue), Some(controller)) = (*this.pending_cue, this.cue_controller.as_mut()) {
+            if this.cues.play(cue, controller.as_mut()) {
+                *this.pending_cue = None;
+            }
         }
 
         Poll::Pending
     }
 }
 
+impl<Shared, Return, MkConnected, MkDisconnected, MkDisabled, MkAutonomous, MkDriver>
+    CompetitionRuntime<
+        Shared,
+        Return,
+        MkConnected,
+        MkDisconnected,
+        MkDisabled,
+        MkAutonomous,
+        MkDriver,
+    >
+where
+    MkConnected:
+        for<'t> FnMut(&'t mut Shared) -> Pin<Box<dyn Future<Output = ControlFlow<Return>> + 't>>,
+    MkDisconnected:
+        for<'t> FnMut(&'t mut Shared) -> Pin<Box<dyn Future<Output = ControlFlow<Return>> + 't>>,
+    MkDisabled:
+        for<'t> FnMut(&'t mut Shared) -> Pin<Box<dyn Future<Output = ControlFlow<Return>> + 't>>,
+    MkAutonomous:
+        for<'t> FnMut(&'t mut Shared) -> Pin<Box<dyn Future<Output = ControlFlow<Return>> + 't>>,
+    MkDriver:
+        for<'t> FnMut(&'t mut Shared) -> Pin<Box<dyn Future<Output = ControlFlow<Return>> + 't>>,
+{
+    /// Plays the given rumble cues on `controller` when the competition changes phase.
+    ///
+    /// See [`PhaseCues`] for more information.
+    #[must_use]
+    pub fn with_cues(mut self, controller: impl CueController + 'static, cues: PhaseCues) -> Self {
+        self.cues = cues;
+        self.cue_controller = Some(Box::new(controller));
+        self
+    }
+}
+
 impl<Shared, Return>
     CompetitionRuntime<
         Shared,
@@ -459,6 +719,10 @@ where
             phase: CompetitionRuntimePhase::Initial,
             task: None,
             shared: UnsafeCell::new(self.shared),
+            cues: PhaseCues::new(),
+            cue_controller: None,
+            pending_cue: None,
+            driver_start: None,
             _pin: PhantomPinned,
         }
     }
@@ -728,6 +992,85 @@ pub trait CompeteExt: Compete {
             .while_driving(|s| Box::pin(async { ControlFlow::Continue(s.driver().await) }))
             .finish()
     }
+
+    /// Runs the handler for a single competition phase, regardless of the current competition state.
+    ///
+    /// This is intended for testing robot code without a competition switch or field controller.
+    /// Unlike [`CompeteExt::compete`], the handler is not interrupted when the competition mode
+    /// changes.
+    ///
+    /// If `duration` is `Some`, the handler is raced against a timeout, and is cancelled (dropped)
+    /// if it has not finished once the duration has elapsed. This mimics how the competition runtime
+    /// interrupts a phase at the end of a match period. If `duration` is `None`, the handler runs
+    /// to completion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    ///
+    /// use vexide::prelude::*;
+    /// use vexide::core::competition::CompetitionPhase;
+    ///
+    /// struct Robot;
+    ///
+    /// impl Compete for Robot {
+    ///     async fn autonomous(&mut self) {
+    ///         println!("Autonomous!");
+    ///     }
+    /// }
+    ///
+    /// #[vexide::main]
+    /// async fn main(_peripherals: Peripherals) {
+    ///     let mut robot = Robot;
+    ///
+    ///     // Run the autonomous routine, enforcing a 15 second limit.
+    ///     robot
+    ///         .run_phase(CompetitionPhase::Autonomous, Some(Duration::from_secs(15)))
+    ///         .await;
+    /// }
+    /// ```
+    fn run_phase(
+        &mut self,
+        phase: CompetitionPhase,
+        duration: Option<Duration>,
+    ) -> impl Future<Output = ()> {
+        let deadline = duration.map(|duration| Instant::now() + duration);
+
+        async move {
+            match phase {
+                CompetitionPhase::Connected => with_deadline(self.connected(), deadline).await,
+                CompetitionPhase::Disconnected => {
+                    with_deadline(self.disconnected(), deadline).await
+                }
+                CompetitionPhase::Disabled => with_deadline(self.disabled(), deadline).await,
+                CompetitionPhase::Autonomous => with_deadline(self.autonomous(), deadline).await,
+                CompetitionPhase::Driver => with_deadline(self.driver(), deadline).await,
+            }
+        }
+    }
+}
+
+/// Polls `future` until it completes or `deadline` is reached.
+async fn with_deadline(future: impl Future<Output = ()>, deadline: Option<Instant>) {
+    let mut future = pin!(future);
+
+    core::future::poll_fn(|cx| {
+        if future.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(());
+        }
+
+        match deadline {
+            Some(deadline) if Instant::now() >= deadline => Poll::Ready(()),
+            Some(_) => {
+                // There is no reactor in this crate to wake us up at the deadline.
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            None => Poll::Pending,
+        }
+    })
+    .await;
 }
 
 impl<R: Compete> CompeteExt for R {}
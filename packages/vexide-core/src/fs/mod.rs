@@ -538,12 +538,12 @@ impl Metadata {
     ///
     /// let metadata = fs::metadata("foo.txt")?;
     ///
-    /// assert_eq!(0, metadata.len());
+    /// assert_eq!(Some(0), metadata.len());
     /// ```
     #[allow(clippy::len_without_is_empty)]
     #[must_use]
     pub fn len(&self) -> Option<u64> {
-        self.file_type.is_dir.then_some(self.size)
+        self.file_type.is_file().then_some(self.size)
     }
 }
 
@@ -665,6 +665,43 @@ impl File {
         Metadata::from_fd(self.fd)
     }
 
+    /// Returns the size of the file in bytes.
+    ///
+    /// This is equivalent to the length returned by [`File::metadata`], but doesn't require
+    /// querying the rest of the file's metadata.
+    ///
+    /// # Errors
+    ///
+    /// * [`InvalidData`]: Internal filesystem error occurred.
+    ///
+    /// [`InvalidData`]: io::ErrorKind::InvalidData
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vexide::core::fs::File;
+    ///
+    /// let file = File::open("config.txt")?;
+    /// println!("config.txt is {} bytes long", file.size()?);
+    /// ```
+    pub fn size(&self) -> io::Result<u64> {
+        let size = unsafe { vex_sdk::vexFileSize(self.fd) };
+
+        size.try_into()
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Failed to get file size"))
+    }
+
+    /// Returns `true` if the file contains no data.
+    ///
+    /// # Errors
+    ///
+    /// * [`InvalidData`]: Internal filesystem error occurred.
+    ///
+    /// [`InvalidData`]: io::ErrorKind::InvalidData
+    pub fn is_empty(&self) -> io::Result<bool> {
+        Ok(self.size()? == 0)
+    }
+
     /// Attempts to sync all OS-internal file content and metadata to disk.
     ///
     /// This function will attempt to ensure that all in-memory data reaches the
@@ -0,0 +1,147 @@
+//! Consistent Overhead Byte Stuffing (COBS) framing.
+//!
+//! COBS is an encoding that removes every `0x00` byte from a packet at the cost of at most
+//! one byte of overhead per 254 bytes of data. Since an encoded packet never contains a zero
+//! byte, `0x00` can then be used as an unambiguous packet delimiter.
+//!
+//! This is used by [`StdoutLock::write_packet`](super::StdoutLock::write_packet) to allow
+//! binary telemetry to share the serial line with regular text output. See [`SerialEncoding`](super::SerialEncoding)
+//! for a description of the framing that is written to the serial port.
+
+use no_std_io::io::{self, Write};
+
+/// The largest number of data bytes that can follow a single COBS code byte.
+const MAX_BLOCK_LEN: usize = 254;
+
+/// Returns the maximum length of a `len`-byte packet after COBS encoding.
+///
+/// This does not include any delimiter bytes surrounding the packet.
+#[must_use]
+pub const fn max_encoded_len(len: usize) -> usize {
+    len + len / MAX_BLOCK_LEN + 1
+}
+
+/// Encodes `data` into `out` using COBS, returning the number of bytes written.
+///
+/// The encoded output contains no `0x00` bytes and is not followed by a delimiter.
+///
+/// # Panics
+///
+/// Panics if `out` is shorter than [`max_encoded_len(data.len())`](max_encoded_len).
+pub fn encode(data: &[u8], out: &mut [u8]) -> usize {
+    assert!(
+        out.len() >= max_encoded_len(data.len()),
+        "COBS output buffer is too small"
+    );
+
+    let mut code_index = 0;
+    let mut write_index = 1;
+    let mut code = 1;
+
+    for &byte in data {
+        if byte == 0 {
+            out[code_index] = code;
+            code_index = write_index;
+            write_index += 1;
+            code = 1;
+        } else {
+            out[write_index] = byte;
+            write_index += 1;
+            code += 1;
+
+            if usize::from(code) == MAX_BLOCK_LEN + 1 {
+                out[code_index] = code;
+                code_index = write_index;
+                write_index += 1;
+                code = 1;
+            }
+        }
+    }
+
+    out[code_index] = code;
+
+    write_index
+}
+
+/// Encodes `data` using COBS and writes it to `writer` without allocating.
+///
+/// The packet is encoded one block at a time using a small stack buffer, so this is suitable
+/// for packets of any size.
+pub(crate) fn encode_to<W: Write + ?Sized>(writer: &mut W, data: &[u8]) -> io::Result<()> {
+    // One code byte followed by up to 254 data bytes.
+    let mut block = [0u8; MAX_BLOCK_LEN + 1];
+    let mut block_len = 0;
+
+    for &byte in data {
+        if byte == 0 {
+            block[0] = (block_len + 1) as u8;
+            writer.write_all(&block[..=block_len])?;
+            block_len = 0;
+        } else {
+            block_len += 1;
+            block[block_len] = byte;
+
+            if block_len == MAX_BLOCK_LEN {
+                block[0] = 0xFF;
+                writer.write_all(&block)?;
+                block_len = 0;
+            }
+        }
+    }
+
+    block[0] = (block_len + 1) as u8;
+    writer.write_all(&block[..=block_len])
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::{encode, encode_to, max_encoded_len};
+
+    fn encode_to_vec(data: &[u8]) -> Vec<u8> {
+        let mut out = vec![0; max_encoded_len(data.len())];
+        let len = encode(data, &mut out);
+        out.truncate(len);
+        out
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(encode_to_vec(&[]), [0x01]);
+    }
+
+    #[test]
+    fn zeros() {
+        assert_eq!(
+            encode_to_vec(&[0x11, 0x00, 0x00, 0x22, 0x33, 0x00]),
+            [0x02, 0x11, 0x01, 0x03, 0x22, 0x33, 0x01]
+        );
+        assert_eq!(encode_to_vec(&[0x00]), [0x01, 0x01]);
+    }
+
+    #[test]
+    fn full_block() {
+        let data: Vec<u8> = (1..=254).collect();
+        let encoded = encode_to_vec(&data);
+
+        assert_eq!(encoded.len(), max_encoded_len(data.len()));
+        assert_eq!(encoded[0], 0xFF);
+        assert_eq!(encoded[1..255], data[..]);
+        assert_eq!(encoded[255], 0x01);
+    }
+
+    #[test]
+    fn encode_matches_encode_to() {
+        let long: Vec<u8> = (0..600u32).map(|i| (i % 256) as u8).collect();
+        let full_block: Vec<u8> = (1..=254).collect();
+
+        for data in [&[][..], &[0x00], &[0x11, 0x00, 0x22], &full_block, &long] {
+            let mut written = Vec::new();
+            encode_to(&mut written, data).unwrap();
+
+            assert_eq!(written, encode_to_vec(data));
+            assert!(!written.contains(&0x00));
+        }
+    }
+}
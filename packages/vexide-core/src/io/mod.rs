@@ -2,9 +2,12 @@
 //!
 //! This module aims to provide a very similar API to the Rust standard library's `std::io` module.
 
+pub mod cobs;
 mod stdio;
 
 #[doc(inline)]
 pub use no_std_io::io::*;
-pub(crate) use stdio::STDIO_CHANNEL;
-pub use stdio::{dbg, print, println, stdin, stdout, Stdin, StdinLock, Stdout, StdoutLock};
+pub use stdio::{
+    dbg, print, println, readline, stdin, stdout, SerialEncoding, Stdin, StdinLock, Stdout,
+    StdoutLock, STDOUT_SERIAL_CHANNEL,
+};
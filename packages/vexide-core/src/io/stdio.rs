@@ -1,23 +1,73 @@
+use alloc::{string::String, vec::Vec};
+
 use no_std_io::io::{self, Write};
-use vex_sdk::{vexSerialReadChar, vexSerialWriteBuffer};
+use vex_sdk::{vexSerialReadChar, vexSerialWriteBuffer, vexTasksRun};
 
+use super::cobs;
 use crate::sync::{Mutex, MutexGuard};
 
-pub(crate) const STDIO_CHANNEL: u32 = 1;
+/// The VEXos serial channel used for standard input and output.
+///
+/// Output from [`print!`], [`println!`], [`dbg!`], and [`stdout`] is written to this channel, and
+/// [`stdin`] reads from it. Channel 1 is the brain's "user" serial port, which is exposed to
+/// the host computer over USB (or wirelessly through a controller) and is read by tools such as
+/// `cargo-v5 terminal`. Other channels are used by VEXos for its own communication with the host
+/// (such as uploading programs) and should not be written to by user code.
+///
+/// These channels are separate from Smart Port serial devices. A `SerialPort` created from a Smart
+/// Port communicates over that port's RS-485 connection and never shares data with this channel, so
+/// it is always safe to use both at the same time. However, writing to this channel directly with
+/// `vex_sdk` functions will interleave with (and may corrupt) text printed through [`stdout`].
+pub const STDOUT_SERIAL_CHANNEL: u32 = 1;
 
-static STDOUT: Mutex<StdoutRaw> = Mutex::new(StdoutRaw);
+static STDOUT: Mutex<StdoutRaw> = Mutex::new(StdoutRaw {
+    encoding: SerialEncoding::Raw,
+});
 static STDIN: Mutex<StdinRaw> = Mutex::new(StdinRaw);
 
+/// The framing used for binary packets written with [`StdoutLock::write_packet`].
+///
+/// Regular text output (such as [`println!`]) is always written as-is. This setting only
+/// affects how packets are framed, which determines whether a host tool can tell binary
+/// data apart from text sent over the same serial line.
+///
+/// # Framing
+///
+/// - [`SerialEncoding::Raw`] writes packet bytes directly with no framing. This is only
+///   suitable when the serial line is used exclusively for binary data (or exclusively for
+///   text), since the host has no way of telling where a packet begins or ends.
+/// - [`SerialEncoding::Cobs`] encodes each packet using [Consistent Overhead Byte Stuffing](super::cobs)
+///   and surrounds it with `0x00` delimiter bytes, producing `0x00 <encoded packet> 0x00`.
+///
+/// # Distinguishing Streams on the Host
+///
+/// Text printed by a program never contains a `0x00` byte, and a COBS-encoded packet never
+/// contains one either. A host tool reading the serial line can therefore treat every byte
+/// as text until it encounters a `0x00`, then collect bytes up to the next `0x00` and
+/// COBS-decode them as a single binary packet before returning to text mode. Empty frames
+/// (two adjacent delimiters) should be ignored.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SerialEncoding {
+    /// Packets are written without any framing.
+    #[default]
+    Raw,
+
+    /// Packets are COBS-encoded and delimited by `0x00` bytes.
+    Cobs,
+}
+
 /// A handle to a raw instance of the serial output stream of this program.
 ///
 /// This handle is not synchronized or buffered in any fashion. Constructed via
 /// the `stdout_raw` function.
-struct StdoutRaw;
+struct StdoutRaw {
+    encoding: SerialEncoding,
+}
 
 impl io::Write for StdoutRaw {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         let written =
-            unsafe { vexSerialWriteBuffer(STDIO_CHANNEL, buf.as_ptr(), buf.len() as u32) };
+            unsafe { vexSerialWriteBuffer(STDOUT_SERIAL_CHANNEL, buf.as_ptr(), buf.len() as u32) };
 
         if written == -1 {
             return Err(io::Error::new(
@@ -44,6 +94,53 @@ pub struct StdoutLock<'a> {
     inner: MutexGuard<'a, StdoutRaw>,
 }
 
+impl StdoutLock<'_> {
+    /// Returns the encoding currently used for packets written with [`StdoutLock::write_packet`].
+    #[must_use]
+    pub fn encoding(&self) -> SerialEncoding {
+        self.inner.encoding
+    }
+
+    /// Sets the encoding used for packets written with [`StdoutLock::write_packet`].
+    ///
+    /// This setting is shared by every handle to the serial output stream and persists
+    /// after the lock is released.
+    pub fn set_encoding(&mut self, encoding: SerialEncoding) {
+        self.inner.encoding = encoding;
+    }
+
+    /// Writes a binary packet to the serial output stream using the current [`SerialEncoding`].
+    ///
+    /// When using [`SerialEncoding::Cobs`], the packet is framed so that a host tool can
+    /// separate it from any text sent over the same serial line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the serial output stream fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vexide::core::io::{stdout, SerialEncoding};
+    ///
+    /// let mut stdout = stdout().lock().await;
+    /// stdout.set_encoding(SerialEncoding::Cobs);
+    ///
+    /// // Binary telemetry and text can now be mixed on the same channel.
+    /// stdout.write_packet(&[0x01, 0x00, 0x7F])?;
+    /// ```
+    pub fn write_packet(&mut self, packet: &[u8]) -> io::Result<()> {
+        match self.inner.encoding {
+            SerialEncoding::Raw => self.inner.write_all(packet),
+            SerialEncoding::Cobs => {
+                self.inner.write_all(&[0])?;
+                cobs::encode_to(&mut *self.inner, packet)?;
+                self.inner.write_all(&[0])
+            }
+        }
+    }
+}
+
 impl Write for StdoutLock<'_> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.inner.write(buf)
@@ -97,7 +194,7 @@ impl io::Read for StdinRaw {
 
         // Little but cursed, but hey it gets the job done...
         while {
-            byte = unsafe { vexSerialReadChar(STDIO_CHANNEL) };
+            byte = unsafe { vexSerialReadChar(STDOUT_SERIAL_CHANNEL) };
             byte != -1
         } {
             if let Some(next) = iterator.next() {
@@ -156,6 +253,56 @@ pub const fn stdin() -> Stdin {
     Stdin(())
 }
 
+/// Reads a line of text from the serial input stream, waiting until a full line is received.
+///
+/// Bytes are read from [`STDOUT_SERIAL_CHANNEL`] until a newline (`\n`) is received. The
+/// returned string does not include the newline, or a carriage return (`\r`) directly before
+/// it. Any invalid UTF-8 is replaced with [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER).
+///
+/// # Blocking
+///
+/// This function blocks the *entire* program, including all other async tasks, until a line
+/// has been received. It is intended for interactive utilities driven from a serial terminal
+/// (such as manually tuning a mechanism) and should not be used while the robot needs to
+/// keep running.
+///
+/// # Panics
+///
+/// Panics if stdin is already locked.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::core::io::readline;
+///
+/// print!("Enter a new kP value: ");
+/// let kp: f64 = readline().trim().parse().unwrap_or(0.0);
+/// println!("kP set to {kp}");
+/// ```
+#[must_use]
+pub fn readline() -> String {
+    let _stdin = STDIN
+        .try_lock()
+        .expect("Attempted to read a line while stdin was already locked.");
+
+    let mut line = Vec::new();
+
+    loop {
+        match unsafe { vexSerialReadChar(STDOUT_SERIAL_CHANNEL) } {
+            // No data has been received yet, so let VEXos process incoming serial data.
+            -1 => unsafe { vexTasksRun() },
+            byte if byte as u8 == b'\n' => break,
+            byte => line.push(byte as u8),
+        }
+    }
+
+    if line.last() == Some(&b'\r') {
+        line.pop();
+    }
+
+    String::from_utf8_lossy(&line).into_owned()
+}
+
 #[macro_export]
 /// Prints a message to the standard output and appends a newline.
 macro_rules! println {
@@ -11,6 +11,7 @@
 
 #![no_std]
 #![feature(never_type)]
+#![cfg_attr(test, feature(noop_waker))]
 
 extern crate alloc;
 
@@ -2,10 +2,17 @@
 //!
 //! This module provides utilities for for interacting and retrieving
 //! information from VEXos.
+//!
+//! Basic battery status is also available here for programs that don't depend on
+//! `vexide-devices`. The `battery` module in `vexide-devices` provides the same readings in
+//! volts and amps.
 
 use core::fmt;
 
-use vex_sdk::vexSystemVersion;
+use vex_sdk::{
+    vexBatteryCapacityGet, vexBatteryCurrentGet, vexBatteryTemperatureGet, vexBatteryVoltageGet,
+    vexSystemVersion,
+};
 
 /// A VEXos version
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
@@ -31,6 +38,24 @@ impl fmt::Display for Version {
 }
 
 /// Returns the current VEXos version.
+///
+/// This is the version of the firmware running on the Brain. The SDK functions used by vexide
+/// are provided by VEXos itself through a jump table rather than being linked into the program,
+/// so this is also the version of the SDK that is available at runtime. It can be compared
+/// against a known [`Version`] to detect firmware-dependent behavior.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::core::os::{system_version, Version};
+///
+/// let version = system_version();
+/// println!("Running VEXos {version}");
+///
+/// if version >= (Version { major: 1, minor: 1, build: 4, beta: 0 }) {
+///     // Use newer firmware features.
+/// }
+/// ```
 #[must_use]
 pub fn system_version() -> Version {
     let version_bytes = unsafe { vexSystemVersion() }.to_be_bytes();
@@ -41,3 +66,61 @@ pub fn system_version() -> Version {
         beta: version_bytes[3],
     }
 }
+
+/// Returns the robot's battery voltage in millivolts.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::core::os::battery_voltage_mv;
+///
+/// println!("Battery voltage: {}mV", battery_voltage_mv());
+/// ```
+#[must_use]
+pub fn battery_voltage_mv() -> u32 {
+    unsafe { vexBatteryVoltageGet() as u32 }
+}
+
+/// Returns the electric current drawn from the robot's battery in milliamps.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::core::os::battery_current_ma;
+///
+/// println!("Drawing {}mA", battery_current_ma());
+/// ```
+#[must_use]
+pub fn battery_current_ma() -> i32 {
+    unsafe { vexBatteryCurrentGet() }
+}
+
+/// Returns the robot's remaining battery capacity as a percentage from 0 to 100.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::core::os::battery_percent;
+///
+/// if battery_percent() < 20 {
+///     println!("Warning: Low battery!");
+/// }
+/// ```
+#[must_use]
+pub fn battery_percent() -> u8 {
+    unsafe { vexBatteryCapacityGet() as u8 }
+}
+
+/// Returns the internal temperature of the robot's battery in degrees Celsius.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::core::os::battery_temperature;
+///
+/// println!("Battery temperature: {}°C", battery_temperature());
+/// ```
+#[must_use]
+pub fn battery_temperature() -> f64 {
+    unsafe { vexBatteryTemperatureGet() }
+}
@@ -5,9 +5,30 @@ use core::{convert::Infallible, fmt::Debug, time::Duration};
 
 use vex_sdk::{vexSerialWriteFree, vexSystemExitRequest, vexTasksRun};
 
-use crate::{io, time::Instant};
+use crate::{
+    io::{self, Write},
+    time::Instant,
+};
 
 /// A trait that can be implemented for arbitrary return types in the main function.
+///
+/// # Returning Errors
+///
+/// [`Result`] implements this trait for any error type implementing [`Debug`]. If the main
+/// function returns an [`Err`], the error is printed to stdout using its [`Debug`]
+/// implementation. This includes error types from crates such as `anyhow`, whose [`Debug`]
+/// output contains the full chain of context messages:
+///
+/// ```ignore
+/// use anyhow::Context;
+///
+/// #[vexide::main]
+/// async fn main(peripherals: Peripherals) -> anyhow::Result<()> {
+///     let file = File::open("config.txt").context("failed to open config file")?;
+///     // ...
+///     Ok(())
+/// }
+/// ```
 pub trait Termination {
     /// Run specific termination logic.
     /// Unlike in the standard library, this function does not return a status code.
@@ -33,6 +54,64 @@ impl<T: Termination, E: Debug> Termination for Result<T, E> {
 
 const FLUSH_TIMEOUT: Duration = Duration::from_millis(15);
 
+/// Returns the [`Instant`] at which the user program started.
+///
+/// [`Instant`]s are measured relative to the start of the program, so this is always the
+/// earliest possible [`Instant`]. It is useful for expressing times relative to the program's
+/// start, such as with [`Instant::duration_since`].
+///
+/// # Examples
+///
+/// ```
+/// use vexide::core::{program, time::Instant};
+///
+/// let elapsed = Instant::now().duration_since(program::start_time());
+/// println!("Program has been running for {elapsed:?}");
+/// ```
+#[must_use]
+pub const fn start_time() -> Instant {
+    Instant::from_ticks(0)
+}
+
+/// Returns the time elapsed since the user program started.
+///
+/// This differs from [`time::uptime`](crate::time::uptime), which measures how long the
+/// brain has been powered on rather than how long the current program has been running.
+///
+/// # Examples
+///
+/// ```
+/// use vexide::core::program;
+///
+/// println!("[{:?}] Program is running", program::uptime());
+/// ```
+#[must_use]
+pub fn uptime() -> Duration {
+    Instant::now().duration_since(start_time())
+}
+
+/// Exits the program, reporting a status code over serial.
+///
+/// VEXos does not have a concept of program exit status, so `code` cannot be passed to the
+/// operating system. Instead, if `code` is non-zero, a line of the form `Exited with code {code}`
+/// is printed to stdout before exiting, allowing tools monitoring the serial output to distinguish
+/// between successful and unsuccessful exits. A `code` of zero behaves identically to [`exit`].
+///
+/// `cargo v5 run` watches the program's serial output for this line, and reports the run as
+/// failed with the printed code when it sees a non-zero exit. The default panic handler exits
+/// with code `1`, so a panicking program is also reported as failed.
+///
+/// Like [`exit`], this function waits up to 15mS for the serial buffer to flush.
+pub fn exit_with_code(code: i32) -> ! {
+    if code != 0 {
+        if let Some(mut stdout) = io::stdout().try_lock() {
+            _ = writeln!(stdout, "Exited with code {code}");
+        }
+    }
+
+    exit()
+}
+
 /// Exits the program using vexSystemExitRequest.
 /// This function will not instantly exit the program,
 /// but will instead wait up to 15mS to force the serial buffer to flush.
@@ -45,7 +124,9 @@ pub fn exit() -> ! {
             vexTasksRun();
 
             // If the buffer has been fully flushed, exit the loop
-            if vexSerialWriteFree(io::STDIO_CHANNEL) == (io::Stdout::INTERNAL_BUFFER_SIZE as i32) {
+            if vexSerialWriteFree(io::STDOUT_SERIAL_CHANNEL)
+                == (io::Stdout::INTERNAL_BUFFER_SIZE as i32)
+            {
                 break;
             }
         }
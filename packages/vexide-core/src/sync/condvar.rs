@@ -121,6 +121,11 @@ impl Condvar {
     }
 
     /// Waits for a notification on the condition variable.
+    ///
+    /// The mutex guarded by `guard` is unlocked while waiting, and is locked again before the
+    /// returned future completes. Because another task may modify the protected data between
+    /// being notified and reacquiring the lock, the condition being waited for should be
+    /// re-checked in a loop.
     pub fn wait<'a, T>(&'a self, guard: MutexGuard<'a, T>) -> CondvarWaitFuture<'a, T> {
         // SAFETY: we can unlock the mutex because we guarantee that it will not be used again until we safely lock it again.
         unsafe {
@@ -134,12 +139,26 @@ impl Condvar {
     }
 
     /// Notify one task waiting on the condition variable.
+    ///
+    /// If no tasks are currently waiting, this does nothing. Notifications are not stored for
+    /// tasks that call [`Condvar::wait`] later.
     pub fn notify_one(&self) {
+        if self.waiting.load(Ordering::Acquire) == 0 {
+            return;
+        }
+
         self.state.store(Self::NOTIFIED_ONE, Ordering::Release);
     }
 
     /// Notify all tasks waiting on the condition variable.
+    ///
+    /// If no tasks are currently waiting, this does nothing. Notifications are not stored for
+    /// tasks that call [`Condvar::wait`] later.
     pub fn notify_all(&self) {
+        if self.waiting.load(Ordering::Acquire) == 0 {
+            return;
+        }
+
         self.state.store(Self::NOTIFIED_ALL, Ordering::Release);
     }
 }
@@ -7,11 +7,17 @@ mod condvar;
 mod lazy;
 mod mutex;
 mod once;
+mod once_cell;
 mod rwlock;
+mod semaphore;
+#[cfg(test)]
+mod test_utils;
 
 pub use barrier::{Barrier, BarrierWaitFuture};
 pub use condvar::{Condvar, CondvarWaitFuture};
 pub use lazy::LazyLock;
 pub use mutex::{Mutex, MutexGuard, MutexLockFuture, RawMutex};
 pub use once::{Once, OnceLock};
+pub use once_cell::OnceCell;
 pub use rwlock::{RwLock, RwLockReadFuture, RwLockReadGuard, RwLockWriteFuture, RwLockWriteGuard};
+pub use semaphore::{Semaphore, SemaphoreAcquireFuture, SemaphorePermit};
@@ -1,6 +1,6 @@
-use core::{cell::UnsafeCell, error::Error, fmt::Debug, mem::MaybeUninit};
+use core::{error::Error, fmt::Debug, future::Future};
 
-use super::mutex::Mutex;
+use super::{mutex::Mutex, OnceCell};
 
 /// A low-level synchronization primitive for one-time global execution.
 ///
@@ -61,74 +61,50 @@ impl Debug for Once {
 /// A synchronization primitive which can be used to run initialization code once.
 /// This type is thread safe and can be used in statics.
 /// All functions that can block are async.
+///
+/// This is a [`OnceCell`] whose initialization functions are all async, so tasks calling them
+/// wait for any initialization already in progress rather than panicking.
 pub struct OnceLock<T> {
-    inner: Once,
-    data: UnsafeCell<MaybeUninit<T>>,
+    cell: OnceCell<T>,
 }
-unsafe impl<T: Send> Send for OnceLock<T> {}
-unsafe impl<T: Send + Sync> Sync for OnceLock<T> {}
 impl<T> OnceLock<T> {
     /// Creates a new uninitialized [`OnceLock`].
     #[must_use]
     pub const fn new() -> Self {
         Self {
-            inner: Once::new(),
-            data: UnsafeCell::new(MaybeUninit::uninit()),
+            cell: OnceCell::new(),
         }
     }
 
     /// Get a reference to the data in the [`OnceLock`] if it has been initialized.
     pub fn get(&self) -> Option<&T> {
-        if self.inner.is_completed() {
-            Some(unsafe { &*(*self.data.get()).as_ptr() })
-        } else {
-            None
-        }
+        self.cell.get()
     }
 
     /// Get a mutable reference to the data in the [`OnceLock`] if it has been initialized.
     pub fn get_mut(&mut self) -> Option<&mut T> {
-        if self.inner.is_completed() {
-            Some(unsafe { &mut *(*self.data.get()).as_mut_ptr() })
-        } else {
-            None
-        }
+        self.cell.get_mut()
     }
 
     /// Attempt to set the data in the [`OnceLock`] if it has not been initialized.
     ///
     /// # Errors
     ///
-    /// If the data in this [`OnceLock`] is already initialized, the `data` parameter is returned as an error.
+    /// If the data in this [`OnceLock`] is already initialized, or another task is in the middle
+    /// of initializing it, the `data` parameter is returned as an error.
     pub fn set(&self, data: T) -> Result<(), T> {
-        if self.inner.is_completed() {
-            return Err(data);
-        }
-
-        Ok(())
+        self.cell.set(data)
     }
 
     /// Consumes the [`OnceLock`] and returns the inner data if it has been initialized.
     pub fn into_inner(self) -> Option<T> {
-        if self.inner.is_completed() {
-            Some(unsafe { (*self.data.get()).as_ptr().read() })
-        } else {
-            None
-        }
+        self.cell.into_inner()
     }
 
     /// Move the data out of the [`OnceLock`] if it has been initialized.
     /// This will leave the [`OnceLock`] in an uninitialized state.
     pub fn take(&mut self) -> Option<T> {
-        let data = if self.inner.is_completed() {
-            Some(unsafe { (*self.data.get()).as_ptr().read() })
-        } else {
-            None
-        };
-        self.inner = Once::new();
-        *self.data.get_mut() = MaybeUninit::uninit();
-        debug_assert!(!self.inner.is_completed());
-        data
+        self.cell.take()
     }
 
     /// Attempt to set the data in the [`OnceLock`] if it has not been initialized.
@@ -138,22 +114,27 @@ impl<T> OnceLock<T> {
     ///
     /// If the data in this [`OnceLock`] is already initialized, the function returns
     /// a reference to the previously stored data along with the value given as the
-    /// `data` parameter.
-    pub fn try_insert(&self, data: T) -> Result<&T, (&T, T)> {
+    /// `data` parameter. If another task is in the middle of initializing the data, there is
+    /// no stored data to return yet, so `None` is returned along with `data`.
+    pub fn try_insert(&self, data: T) -> Result<&T, (Option<&T>, T)> {
         match self.set(data) {
             Ok(()) => Ok(self.get().unwrap()),
-            Err(data) => Err((self.get().unwrap(), data)),
+            Err(data) => Err((self.get(), data)),
         }
     }
 
     /// Get or initialize the data in the [`OnceLock`].
     /// This function will always return the value stored.
     pub async fn get_or_init(&self, init: impl FnOnce() -> T) -> &T {
-        if let Some(data) = self.get() {
-            return data;
-        }
-        self.init(init).await;
-        unsafe { &*(*self.data.get()).as_ptr() }
+        self.cell.get_or_init_async(async { init() }).await
+    }
+
+    /// Get or initialize the data in the [`OnceLock`] using an async initializer.
+    /// This function will always return the value stored.
+    ///
+    /// This behaves like [`OnceCell::get_or_init_async`]; see its documentation for details.
+    pub async fn get_or_init_async(&self, init: impl Future<Output = T>) -> &T {
+        self.cell.get_or_init_async(init).await
     }
 
     /// Get or try to initialize the data in the [`OnceLock`].
@@ -172,41 +153,16 @@ impl<T> OnceLock<T> {
         if let Some(data) = self.get() {
             return Ok(data);
         }
-        self.try_init(init).await?;
-        debug_assert!(self.inner.is_completed());
-        Ok(unsafe { &*(*self.data.get()).as_ptr() })
-    }
-
-    async fn init(&self, init: impl FnOnce() -> T) {
-        self.inner
-            .call_once(|| unsafe {
-                (*self.data.get()).write(init());
-            })
-            .await;
-        debug_assert!(self.inner.is_completed());
-    }
-
-    async fn try_init<E: Error>(&self, init: impl FnOnce() -> Result<T, E>) -> Result<(), E> {
-        match init() {
-            Ok(data) => {
-                self.inner
-                    .call_once(|| unsafe {
-                        (*self.data.get()).write(data);
-                    })
-                    .await;
-                Ok(())
-            }
-            Err(e) => Err(e),
-        }
+
+        let data = init()?;
+        Ok(self.cell.get_or_init_async(async { data }).await)
     }
 }
 impl<T: Clone> Clone for OnceLock<T> {
     fn clone(&self) -> Self {
-        let new = Self::new();
-        if let Some(data) = self.get() {
-            unsafe { new.set(data.clone()).unwrap_unchecked() };
+        Self {
+            cell: self.cell.clone(),
         }
-        new
     }
 }
 impl<T: Debug> Debug for OnceLock<T> {
@@ -221,18 +177,11 @@ impl<T> Default for OnceLock<T> {
         Self::new()
     }
 }
-impl<T> Drop for OnceLock<T> {
-    fn drop(&mut self) {
-        if self.inner.is_completed() {
-            unsafe { (*self.data.get()).assume_init_drop() }
-        }
-    }
-}
 impl<T> From<T> for OnceLock<T> {
     fn from(data: T) -> Self {
-        let lock = Self::new();
-        unsafe { lock.set(data).unwrap_unchecked() };
-        lock
+        Self {
+            cell: OnceCell::from(data),
+        }
     }
 }
 
@@ -242,3 +191,40 @@ impl<T: PartialEq> PartialEq for OnceLock<T> {
     }
 }
 impl<T: Eq> Eq for OnceLock<T> {}
+
+#[cfg(test)]
+mod tests {
+    use core::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use super::OnceLock;
+    use crate::sync::test_utils::yield_once;
+
+    #[test]
+    fn try_insert_during_async_init() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let lock = OnceLock::new();
+
+        let mut init = pin!(lock.get_or_init_async(async {
+            yield_once().await;
+            1
+        }));
+        assert!(init.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(lock.get(), None);
+        assert_eq!(lock.set(2), Err(2));
+        assert_eq!(lock.try_insert(3), Err((None, 3)));
+
+        assert_eq!(init.as_mut().poll(&mut cx), Poll::Ready(&1));
+        assert_eq!(lock.try_insert(4), Err((Some(&1), 4)));
+    }
+
+    #[test]
+    fn try_insert_uninitialized() {
+        let lock = OnceLock::new();
+        assert_eq!(lock.try_insert(1), Ok(&1));
+        assert_eq!(lock.get(), Some(&1));
+    }
+}
@@ -0,0 +1,293 @@
+use core::{
+    cell::UnsafeCell,
+    fmt::Debug,
+    future::{poll_fn, Future},
+    mem::{self, MaybeUninit},
+    sync::atomic::{AtomicU8, Ordering},
+    task::Poll,
+};
+
+const UNINIT: u8 = 0;
+const INITIALIZING: u8 = 1;
+const COMPLETE: u8 = 2;
+
+/// A cell which can be written to only once.
+///
+/// Unlike [`OnceLock`](super::OnceLock), a [`OnceCell`] can be initialized synchronously with
+/// [`OnceCell::get_or_init`], so it can be used outside of async code. Initializers that need
+/// to wait on something can use [`OnceCell::get_or_init_async`] instead.
+///
+/// This type is thread safe and can be used in statics.
+///
+/// # Examples
+///
+/// ```
+/// use vexide::core::sync::OnceCell;
+///
+/// static CONFIG: OnceCell<u32> = OnceCell::new();
+///
+/// let config = CONFIG.get_or_init(|| 42);
+/// assert_eq!(*config, 42);
+/// ```
+pub struct OnceCell<T> {
+    state: AtomicU8,
+    data: UnsafeCell<MaybeUninit<T>>,
+}
+unsafe impl<T: Send> Send for OnceCell<T> {}
+unsafe impl<T: Send + Sync> Sync for OnceCell<T> {}
+impl<T> OnceCell<T> {
+    /// Creates a new uninitialized [`OnceCell`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(UNINIT),
+            data: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Get a reference to the data in the [`OnceCell`] if it has been initialized.
+    #[must_use]
+    pub fn get(&self) -> Option<&T> {
+        if self.state.load(Ordering::Acquire) == COMPLETE {
+            Some(unsafe { &*(*self.data.get()).as_ptr() })
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to the data in the [`OnceCell`] if it has been initialized.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if *self.state.get_mut() == COMPLETE {
+            Some(unsafe { self.data.get_mut().assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Attempt to set the data in the [`OnceCell`] if it has not been initialized.
+    ///
+    /// # Errors
+    ///
+    /// If the data in this [`OnceCell`] is already initialized, or another task is in the
+    /// middle of initializing it with [`OnceCell::get_or_init_async`], the `data` parameter
+    /// is returned as an error.
+    pub fn set(&self, data: T) -> Result<(), T> {
+        match self.start_init() {
+            Ok(guard) => {
+                guard.finish(data);
+                Ok(())
+            }
+            Err(_) => Err(data),
+        }
+    }
+
+    /// Get or initialize the data in the [`OnceCell`].
+    /// This function will always return the value stored.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if another task is in the middle of initializing the cell with
+    ///   [`OnceCell::get_or_init_async`], or if `init` itself initializes the cell.
+    ///   Use [`OnceCell::get_or_init_async`] to wait for the other initialization instead.
+    pub fn get_or_init(&self, init: impl FnOnce() -> T) -> &T {
+        match self.start_init() {
+            Ok(guard) => guard.finish(init()),
+            Err(state) => assert!(
+                state == COMPLETE,
+                "OnceCell initialized while another initialization was in progress"
+            ),
+        }
+
+        unsafe { &*(*self.data.get()).as_ptr() }
+    }
+
+    /// Get or initialize the data in the [`OnceCell`] using an async initializer.
+    /// This function will always return the value stored.
+    ///
+    /// This is useful when initialization needs to wait on something, such as a device that
+    /// must be calibrated before its first use. If multiple tasks call this at the same time,
+    /// only one `init` future is run and the other tasks wait for it to complete.
+    /// `init` is not polled at all if the data is already initialized.
+    ///
+    /// If the returned future is dropped before `init` completes, the cell is left
+    /// uninitialized and a later call may initialize it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    /// use vexide::core::sync::OnceCell;
+    ///
+    /// static START_HEADING: OnceCell<f64> = OnceCell::new();
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut imu = InertialSensor::new(peripherals.port_1);
+    ///
+    ///     let heading = START_HEADING
+    ///         .get_or_init_async(async {
+    ///             imu.calibrate().await.unwrap();
+    ///             imu.heading().unwrap()
+    ///         })
+    ///         .await;
+    ///
+    ///     println!("Started at {heading} degrees");
+    /// }
+    /// ```
+    pub async fn get_or_init_async(&self, init: impl Future<Output = T>) -> &T {
+        let guard = poll_fn(|cx| match self.start_init() {
+            Ok(guard) => Poll::Ready(Some(guard)),
+            Err(COMPLETE) => Poll::Ready(None),
+            Err(_) => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        })
+        .await;
+
+        if let Some(guard) = guard {
+            guard.finish(init.await);
+        }
+
+        unsafe { &*(*self.data.get()).as_ptr() }
+    }
+
+    /// Consumes the [`OnceCell`] and returns the inner data if it has been initialized.
+    pub fn into_inner(mut self) -> Option<T> {
+        self.take()
+    }
+
+    /// Move the data out of the [`OnceCell`] if it has been initialized.
+    /// This will leave the [`OnceCell`] in an uninitialized state.
+    pub fn take(&mut self) -> Option<T> {
+        if mem::replace(self.state.get_mut(), UNINIT) == COMPLETE {
+            Some(unsafe { self.data.get_mut().assume_init_read() })
+        } else {
+            None
+        }
+    }
+
+    /// Claims the cell for initialization, returning the current state if it is
+    /// already initialized or being initialized.
+    fn start_init(&self) -> Result<InitGuard<'_, T>, u8> {
+        self.state
+            .compare_exchange(UNINIT, INITIALIZING, Ordering::Acquire, Ordering::Acquire)
+            .map(|_| InitGuard { cell: self })
+    }
+}
+impl<T: Clone> Clone for OnceCell<T> {
+    fn clone(&self) -> Self {
+        let new = Self::new();
+        if let Some(data) = self.get() {
+            unsafe { new.set(data.clone()).unwrap_unchecked() };
+        }
+        new
+    }
+}
+impl<T: Debug> Debug for OnceCell<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OnceCell")
+            .field("data", &self.get())
+            .finish_non_exhaustive()
+    }
+}
+impl<T> Default for OnceCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T> Drop for OnceCell<T> {
+    fn drop(&mut self) {
+        if *self.state.get_mut() == COMPLETE {
+            unsafe { self.data.get_mut().assume_init_drop() }
+        }
+    }
+}
+impl<T> From<T> for OnceCell<T> {
+    fn from(data: T) -> Self {
+        let cell = Self::new();
+        unsafe { cell.set(data).unwrap_unchecked() };
+        cell
+    }
+}
+impl<T: PartialEq> PartialEq for OnceCell<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+impl<T: Eq> Eq for OnceCell<T> {}
+
+/// Marks a [`OnceCell`] as being initialized, and resets it if initialization is cancelled.
+struct InitGuard<'a, T> {
+    cell: &'a OnceCell<T>,
+}
+impl<T> InitGuard<'_, T> {
+    fn finish(self, data: T) {
+        unsafe { (*self.cell.data.get()).write(data) };
+        self.cell.state.store(COMPLETE, Ordering::Release);
+        mem::forget(self);
+    }
+}
+impl<T> Drop for InitGuard<'_, T> {
+    fn drop(&mut self) {
+        self.cell.state.store(UNINIT, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use super::OnceCell;
+    use crate::sync::test_utils::yield_once;
+
+    #[test]
+    fn get_or_init() {
+        let cell = OnceCell::new();
+        assert_eq!(cell.get(), None);
+        assert_eq!(*cell.get_or_init(|| 1), 1);
+        assert_eq!(*cell.get_or_init(|| 2), 1);
+        assert_eq!(cell.set(3), Err(3));
+    }
+
+    #[test]
+    fn set_during_async_init() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let cell = OnceCell::new();
+
+        let mut init = pin!(cell.get_or_init_async(async {
+            yield_once().await;
+            1
+        }));
+        assert!(init.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(cell.get(), None);
+        assert_eq!(cell.set(2), Err(2));
+
+        let mut waiter = pin!(cell.get_or_init_async(async { 3 }));
+        assert!(waiter.as_mut().poll(&mut cx).is_pending());
+
+        assert_eq!(init.as_mut().poll(&mut cx), Poll::Ready(&1));
+        assert_eq!(waiter.as_mut().poll(&mut cx), Poll::Ready(&1));
+    }
+
+    #[test]
+    fn cancelled_async_init() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let cell = OnceCell::new();
+
+        {
+            let mut init = pin!(cell.get_or_init_async(async {
+                yield_once().await;
+                1
+            }));
+            assert!(init.as_mut().poll(&mut cx).is_pending());
+        }
+
+        assert_eq!(cell.set(2), Ok(()));
+        assert_eq!(cell.get(), Some(&2));
+    }
+}
@@ -0,0 +1,211 @@
+use alloc::collections::BTreeMap;
+use core::{
+    fmt::Debug,
+    sync::atomic::{AtomicUsize, Ordering},
+    task::{Poll, Waker},
+};
+
+use futures_core::Future;
+
+use super::RawMutex;
+
+/// A future that resolves to a [`SemaphorePermit`] once enough permits are available.
+/// This is created by [`Semaphore::acquire`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SemaphoreAcquireFuture<'a> {
+    semaphore: &'a Semaphore,
+    permits: usize,
+    /// The key of this future's waker in the semaphore's wait list, if it has registered one.
+    waiter: Option<usize>,
+}
+impl SemaphoreAcquireFuture<'_> {
+    fn unregister(&mut self) {
+        if let Some(key) = self.waiter.take() {
+            self.semaphore.waiters.lock().wakers.remove(&key);
+        }
+    }
+}
+impl<'a> Future for SemaphoreAcquireFuture<'a> {
+    type Output = SemaphorePermit<'a>;
+
+    fn poll(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        let this = self.get_mut();
+
+        if let Some(permit) = this.semaphore.try_acquire(this.permits) {
+            this.unregister();
+            return Poll::Ready(permit);
+        }
+
+        this.semaphore
+            .waiters
+            .lock()
+            .register(&mut this.waiter, cx.waker());
+
+        // Permits may have been released between the failed attempt and registering our
+        // waker, in which case nobody is left to wake us, so we need to check again.
+        match this.semaphore.try_acquire(this.permits) {
+            Some(permit) => {
+                this.unregister();
+                Poll::Ready(permit)
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+impl Drop for SemaphoreAcquireFuture<'_> {
+    fn drop(&mut self) {
+        self.unregister();
+    }
+}
+
+/// The wakers of tasks waiting to acquire permits from a [`Semaphore`].
+///
+/// Each waiting future registers at most one waker, keyed by an ID that it keeps so it can
+/// replace or remove its own waker later.
+struct WaitList {
+    next_key: usize,
+    wakers: BTreeMap<usize, Waker>,
+}
+impl WaitList {
+    const fn new() -> Self {
+        Self {
+            next_key: 0,
+            wakers: BTreeMap::new(),
+        }
+    }
+
+    /// Registers `waker` for the waiter identified by `key`, replacing its previous waker if
+    /// it still has one in the list.
+    fn register(&mut self, key: &mut Option<usize>, waker: &Waker) {
+        if let Some(existing) = key.and_then(|key| self.wakers.get_mut(&key)) {
+            if !existing.will_wake(waker) {
+                existing.clone_from(waker);
+            }
+            return;
+        }
+
+        let new_key = self.next_key;
+        self.next_key = self.next_key.wrapping_add(1);
+        self.wakers.insert(new_key, waker.clone());
+        *key = Some(new_key);
+    }
+}
+
+/// A counting semaphore.
+///
+/// Semaphores hold a number of permits which tasks may acquire and release. Once all permits
+/// have been acquired, tasks attempting to acquire more will wait until some are released. This
+/// can be used to limit how many tasks may use a shared resource at the same time, such as
+/// limiting concurrent motor commands or rate-limiting writes to the SD card.
+///
+/// Tasks waiting on the semaphore are woken when permits are released, but are not guaranteed
+/// to acquire them in the order that they started waiting.
+///
+/// # Examples
+///
+/// ```rust
+/// // Allow at most two tasks to write to the SD card at once.
+/// static SD_CARD: Semaphore = Semaphore::new(2);
+///
+/// for i in 0..10 {
+///     spawn(async move {
+///         let _permit = SD_CARD.acquire(1).await;
+///         log_to_sd_card(i).await;
+///         // The permit is released here when `_permit` is dropped.
+///     })
+///     .detach();
+/// }
+/// ```
+pub struct Semaphore {
+    permits: AtomicUsize,
+    waiters: lock_api::Mutex<RawMutex, WaitList>,
+}
+impl Semaphore {
+    /// Creates a new semaphore with the given number of available permits.
+    #[must_use]
+    pub const fn new(permits: usize) -> Self {
+        Self {
+            permits: AtomicUsize::new(permits),
+            waiters: lock_api::Mutex::const_new(RawMutex::new(), WaitList::new()),
+        }
+    }
+
+    /// Returns the number of permits that are currently available.
+    #[must_use]
+    pub fn available_permits(&self) -> usize {
+        self.permits.load(Ordering::Acquire)
+    }
+
+    /// Acquires `permits` permits from the semaphore, waiting until enough are available.
+    ///
+    /// The returned [`SemaphorePermit`] releases the permits back to the semaphore when dropped.
+    pub const fn acquire(&self, permits: usize) -> SemaphoreAcquireFuture<'_> {
+        SemaphoreAcquireFuture {
+            semaphore: self,
+            permits,
+            waiter: None,
+        }
+    }
+
+    /// Attempts to acquire `permits` permits from the semaphore. This function does not block.
+    ///
+    /// Returns `None` if there are not enough permits available.
+    pub fn try_acquire(&self, permits: usize) -> Option<SemaphorePermit<'_>> {
+        self.permits
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |available| {
+                available.checked_sub(permits)
+            })
+            .ok()
+            .map(|_| SemaphorePermit {
+                semaphore: self,
+                permits,
+            })
+    }
+
+    /// Adds `permits` permits to the semaphore, waking any tasks waiting to acquire them.
+    ///
+    /// This is called automatically when a [`SemaphorePermit`] is dropped, but may also be used
+    /// to increase the total number of permits held by the semaphore.
+    pub fn release(&self, permits: usize) {
+        self.permits.fetch_add(permits, Ordering::AcqRel);
+
+        // Waiters may each need a different number of permits, so wake all of them and let
+        // each one check whether there are now enough available.
+        let wakers = core::mem::take(&mut self.waiters.lock().wakers);
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+}
+impl Debug for Semaphore {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Semaphore")
+            .field("permits", &self.available_permits())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Permits acquired from a [`Semaphore`].
+/// The permits are released back to the semaphore when this is dropped.
+#[derive(Debug)]
+#[must_use = "if unused the permits will immediately be released"]
+#[clippy::has_significant_drop]
+pub struct SemaphorePermit<'a> {
+    semaphore: &'a Semaphore,
+    permits: usize,
+}
+impl SemaphorePermit<'_> {
+    /// Returns the number of permits held by this guard.
+    #[must_use]
+    pub const fn permits(&self) -> usize {
+        self.permits
+    }
+}
+impl Drop for SemaphorePermit<'_> {
+    fn drop(&mut self) {
+        self.semaphore.release(self.permits);
+    }
+}
@@ -0,0 +1,17 @@
+//! Helpers shared by the synchronization primitive tests.
+
+use core::{future::poll_fn, task::Poll};
+
+/// A future that returns [`Poll::Pending`] the first time it is polled.
+pub async fn yield_once() {
+    let mut yielded = false;
+    poll_fn(|_| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            Poll::Pending
+        }
+    })
+    .await;
+}
@@ -1,6 +1,9 @@
 //! Temporal quantification.
 //!
 //! This module provides an implementation of [`Instant`] built on the VEXos high-resolution timer.
+//!
+//! For measurements that need better than microsecond precision (such as profiling tight loops),
+//! [`HighResolutionTimer`] provides access to the CPU's cycle counter.
 
 use core::{
     fmt,
@@ -19,6 +22,43 @@ use vex_sdk::vexSystemPowerupTimeGet;
 pub struct Instant(u64);
 
 impl Instant {
+    /// The number of ticks per second used by [`Instant::from_ticks`] and [`Instant::as_ticks`].
+    ///
+    /// Each tick is one microsecond.
+    pub const TICK_FREQUENCY: u64 = 1_000_000;
+
+    /// Creates an instant from a number of ticks since the start of the user program.
+    ///
+    /// Ticks occur at a rate of [`Instant::TICK_FREQUENCY`]. This is the inverse of
+    /// [`Instant::as_ticks`], and can be used to reconstruct instants from logged data for
+    /// offline analysis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::core::time::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let ticks = now.as_ticks();
+    ///
+    /// // ... ticks are written to a log file and read back later ...
+    ///
+    /// assert_eq!(Instant::from_ticks(ticks), now);
+    /// ```
+    #[must_use]
+    pub const fn from_ticks(ticks: u64) -> Self {
+        Self(ticks)
+    }
+
+    /// Returns the number of ticks since the start of the user program.
+    ///
+    /// Ticks occur at a rate of [`Instant::TICK_FREQUENCY`]. See [`Instant::from_ticks`] for
+    /// the inverse of this function.
+    #[must_use]
+    pub const fn as_ticks(&self) -> u64 {
+        self.0
+    }
+
     /// Returns an instant corresponding to "now".
     ///
     /// # Examples
@@ -187,3 +227,145 @@ impl fmt::Debug for Instant {
 pub fn uptime() -> Duration {
     Duration::from_micros(unsafe { vexSystemPowerupTimeGet() })
 }
+
+/// Access to the Cortex-A9 cycle counter for sub-microsecond timing.
+///
+/// [`Instant`] is limited to a precision of 1 microsecond. This timer instead reads the ARM
+/// Performance Monitor Unit's cycle counter register (PMCCNTR), which increments once every
+/// CPU clock cycle ([`HighResolutionTimer::FREQUENCY`]), giving a precision of about 1.5
+/// nanoseconds.
+///
+/// # Limitations
+///
+/// - The cycle counter is disabled by default and must be started with
+///   [`HighResolutionTimer::enable`] before use. Until then, every reading will be zero.
+/// - The counter is only 32 bits wide, so it wraps around roughly every 6.4 seconds. Durations
+///   between two [`HighResolutionInstant`]s are only correct if less than one full wrap has
+///   occurred between them. Use [`Instant`] for measuring longer periods.
+/// - The counter keeps running while VEXos handles background tasks, so measurements include
+///   any time spent outside of user code.
+///
+/// On targets other than the V5 brain, the counter always reads zero.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::core::time::HighResolutionTimer;
+///
+/// unsafe {
+///     HighResolutionTimer::enable();
+/// }
+///
+/// let start = HighResolutionTimer::now();
+/// let mut sum = 0u64;
+/// for i in 0..1000 {
+///     sum += i * i;
+/// }
+/// println!("Summed to {sum} in {}ns", start.elapsed().as_nanos());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HighResolutionTimer;
+
+impl HighResolutionTimer {
+    /// The frequency of the cycle counter in Hz, which is the V5 brain's CPU clock frequency.
+    pub const FREQUENCY: u64 = 666_666_666;
+
+    /// Enables and resets the cycle counter.
+    ///
+    /// This sets the enable and cycle counter reset bits of the Performance Monitor Control
+    /// Register (PMCR) and enables the cycle counter in the Count Enable Set Register
+    /// (PMCNTENSET). The counter is not divided, so it increments once every CPU cycle.
+    ///
+    /// # Safety
+    ///
+    /// The PMU registers can only be written from a privileged processor mode, which VEXos user
+    /// programs currently run in. Resetting the counter invalidates any previously captured
+    /// [`HighResolutionInstant`]s, so the caller must ensure that none are in use when this is
+    /// called.
+    #[allow(clippy::missing_const_for_fn)]
+    pub unsafe fn enable() {
+        #[cfg(target_vendor = "vex")]
+        unsafe {
+            // PMCR: enable all counters (E, bit 0) and reset the cycle counter (C, bit 2).
+            core::arch::asm!("mcr p15, 0, {}, c9, c12, 0", in(reg) 0b101u32, options(nostack));
+            // PMCNTENSET: enable the cycle counter (bit 31).
+            core::arch::asm!("mcr p15, 0, {}, c9, c12, 1", in(reg) 1u32 << 31, options(nostack));
+        }
+    }
+
+    /// Returns the current value of the cycle counter.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use]
+    pub fn cycles() -> u32 {
+        #[cfg(target_vendor = "vex")]
+        {
+            let cycles: u32;
+            unsafe {
+                core::arch::asm!(
+                    "mrc p15, 0, {}, c9, c13, 0",
+                    out(reg) cycles,
+                    options(nomem, nostack, preserves_flags)
+                );
+            }
+            cycles
+        }
+
+        #[cfg(not(target_vendor = "vex"))]
+        {
+            0
+        }
+    }
+
+    /// Returns an instant corresponding to "now" on the cycle counter.
+    #[must_use]
+    pub fn now() -> HighResolutionInstant {
+        HighResolutionInstant(Self::cycles())
+    }
+}
+
+/// A reading of the CPU cycle counter taken by [`HighResolutionTimer::now`].
+///
+/// Unlike [`Instant`], these readings wrap around and are not ordered. See
+/// [`HighResolutionTimer`] for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HighResolutionInstant(u32);
+
+impl HighResolutionInstant {
+    /// Returns the raw cycle count of this reading.
+    #[must_use]
+    pub const fn cycles(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the number of CPU cycles elapsed from another reading to this one.
+    ///
+    /// This accounts for the counter wrapping around at most once between the two readings.
+    #[must_use]
+    pub const fn cycles_since(&self, earlier: HighResolutionInstant) -> u32 {
+        self.0.wrapping_sub(earlier.0)
+    }
+
+    /// Returns the amount of time elapsed from another reading to this one.
+    ///
+    /// This accounts for the counter wrapping around at most once between the two readings.
+    #[must_use]
+    pub const fn duration_since(&self, earlier: HighResolutionInstant) -> Duration {
+        let cycles = self.cycles_since(earlier) as u64;
+        Duration::from_nanos(cycles * 1_000_000_000 / HighResolutionTimer::FREQUENCY)
+    }
+
+    /// Returns the amount of time elapsed since this reading.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        HighResolutionTimer::now().duration_since(*self)
+    }
+}
+
+impl Sub<HighResolutionInstant> for HighResolutionInstant {
+    type Output = Duration;
+
+    /// Returns the amount of time elapsed from another reading to this one.
+    fn sub(self, other: HighResolutionInstant) -> Duration {
+        self.duration_since(other)
+    }
+}
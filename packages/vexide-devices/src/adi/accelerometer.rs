@@ -32,10 +32,21 @@
 //! (ground) wires go at the other end, adjacent to the 'B' label on the board. The center wire is for +5
 //! volts. The sensor's mounting holes are electrically isolated from the circuit, meaning it is safe to
 //! mount the device using screws on a robot.
+//!
+//! # Bias
+//!
+//! Each sensor has a slightly different reading at rest. This static bias can be measured with
+//! [`AdiAccelerometer::calibrate_zero`], after which it will be subtracted from every reading.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
 
 use vex_sdk::vexDeviceAdiValueGet;
 
-use super::{analog, AdiDevice, AdiDeviceType, AdiPort};
+use super::{analog, AdiDevice, AdiDeviceType, AdiPort, AdiSampler};
 use crate::PortError;
 
 /// A single axis connection to the 3-axis analog accelerometer.
@@ -43,6 +54,7 @@ use crate::PortError;
 pub struct AdiAccelerometer {
     sensitivity: Sensitivity,
     port: AdiPort,
+    zero: u16,
 }
 
 impl AdiAccelerometer {
@@ -51,7 +63,11 @@ impl AdiAccelerometer {
     pub fn new(port: AdiPort, sensitivity: Sensitivity) -> Self {
         port.configure(AdiDeviceType::Accelerometer);
 
-        Self { sensitivity, port }
+        Self {
+            sensitivity,
+            port,
+            zero: 0,
+        }
     }
 
     /// Returns the configured sensitivity of the ADI accelerometer device.
@@ -68,17 +84,75 @@ impl AdiAccelerometer {
 
     /// Returns the current acceleration measurement for this axis in g (~9.8 m/s/s).
     ///
+    /// If the sensor has been [calibrated](AdiAccelerometer::calibrate_zero), the measured bias is
+    /// subtracted from this reading.
+    ///
     /// # Errors
     ///
     /// - A [`PortError::Disconnected`] error is returned if an ADI expander device was required but not connected.
     /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
     ///   something else was connected.
     pub fn acceleration(&self) -> Result<f64, PortError> {
-        Ok(
-            // Convert 0-4095 to 0-1, then scale to max accel.
-            f64::from(self.raw_acceleration()?) / f64::from(analog::ADC_MAX_VALUE)
-                * self.sensitivity.max_acceleration(),
-        )
+        Ok(self.raw_to_acceleration(self.raw_acceleration()?) - self.bias())
+    }
+
+    /// Converts a raw 0-4095 reading to an acceleration in g.
+    fn raw_to_acceleration(&self, raw: u16) -> f64 {
+        // Convert 0-4095 to 0-1, then scale to max accel.
+        f64::from(raw) / f64::from(analog::ADC_MAX_VALUE) * self.sensitivity.max_acceleration()
+    }
+
+    /// Returns the static bias (in g) measured by [`AdiAccelerometer::calibrate_zero`], or `0.0` if
+    /// the sensor has not been calibrated.
+    #[must_use]
+    pub fn bias(&self) -> f64 {
+        self.raw_to_acceleration(self.zero)
+    }
+
+    /// Measures the static bias of this axis by averaging readings while the sensor is stationary.
+    ///
+    /// The measured bias is stored and subtracted from every subsequent call to
+    /// [`AdiAccelerometer::acceleration`] for the rest of the program, and is also returned in g.
+    /// Calling this function again replaces the previous bias. A `samples` count of `0` is treated
+    /// as `1`.
+    ///
+    /// The sensor must not be moving while this function runs. Note that gravity is measured as
+    /// part of the bias, so after calibrating, a vertical axis will read `0.0` at rest rather than
+    /// `1.0`.
+    ///
+    /// # Latency
+    ///
+    /// ADI ports are only updated every [`ADI_UPDATE_INTERVAL`](super::ADI_UPDATE_INTERVAL), so
+    /// the returned future waits for a new update between each sample, taking around
+    /// `(samples - 1) * 10ms` to complete.
+    ///
+    /// # Errors
+    ///
+    /// - A [`PortError::Disconnected`] error is returned if an ADI expander device was required but not connected.
+    /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
+    ///   something else was connected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    /// use vexide::devices::adi::accelerometer::Sensitivity;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut accel_x = AdiAccelerometer::new(peripherals.adi_a, Sensitivity::Low);
+    ///
+    ///     // Average 50 samples (about half a second) while the robot is stationary.
+    ///     if let Ok(bias) = accel_x.calibrate_zero(50).await {
+    ///         println!("Measured bias: {bias}g");
+    ///     }
+    /// }
+    /// ```
+    pub fn calibrate_zero(&mut self, samples: u16) -> AdiAccelerometerCalibrationFuture<'_> {
+        AdiAccelerometerCalibrationFuture {
+            accelerometer: self,
+            sampler: AdiSampler::new(samples),
+        }
     }
 
     /// Returns the raw acceleration reading from [0, 4096]. This represents an ADC-converted
@@ -100,6 +174,32 @@ impl AdiAccelerometer {
     }
 }
 
+/// A future that measures the static bias of an [`AdiAccelerometer`].
+///
+/// This future is returned by [`AdiAccelerometer::calibrate_zero`], and resolves to the measured
+/// bias in g.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AdiAccelerometerCalibrationFuture<'a> {
+    accelerometer: &'a mut AdiAccelerometer,
+    sampler: AdiSampler,
+}
+
+impl Future for AdiAccelerometerCalibrationFuture<'_> {
+    type Output = Result<f64, PortError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        this.sampler
+            .poll_average(&this.accelerometer.port, cx)
+            .map_ok(|zero| {
+                this.accelerometer.zero = zero;
+                this.accelerometer.bias()
+            })
+    }
+}
+
 /// The jumper state of the accelerometer.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum Sensitivity {
@@ -24,6 +24,12 @@
 //! Using something like a [74HCT125 buffer] inline with the output to convert the 3.3-5V logic addresses both
 //! these problems.
 //!
+//! # Animations
+//!
+//! Strips can be animated with [`AdiAddrLed::animate`], which returns a future that draws a
+//! new frame of a [`LedAnimation`] at the animation's frame rate. A few common animations are
+//! provided: [`RainbowCycle`], [`Breathing`], and [`ColorChase`].
+//!
 //! # `smart_leds_trait` Integration
 //!
 //! When compiled with the `smart_leds_trait` feature, vexide will implement the [`SmartLedsWrite`] trait
@@ -36,9 +42,17 @@
 //! [`SmartLedsWrite`]: https://docs.rs/smart-leds-trait/0.3.0/smart_leds_trait/trait.SmartLedsWrite.html
 
 use alloc::{vec, vec::Vec};
+use core::{
+    f64::consts::TAU,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
 
 use snafu::{ensure, Snafu};
 use vex_sdk::vexDeviceAdiAddrLedSet;
+use vexide_core::{float::Float, time::Instant};
 
 use super::{AdiDevice, AdiDeviceType, AdiPort};
 use crate::{
@@ -147,6 +161,226 @@ impl AdiAddrLed {
 
         Ok(self.buf.len())
     }
+
+    /// Plays an animation on the strip.
+    ///
+    /// This returns a future that draws a new frame of `animation` to the strip every
+    /// [`LedAnimation::frame_interval`]. The future runs forever unless the strip can no longer
+    /// be accessed, in which case it resolves to the error that occurred. It is usually
+    /// spawned as its own task so that the rest of the program can run alongside it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    /// use vexide::devices::adi::addrled::RainbowCycle;
+    /// use core::time::Duration;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let strip = AdiAddrLed::new(peripherals.adi_a, 30).unwrap();
+    ///
+    ///     spawn(strip.animate(RainbowCycle::new(Duration::from_millis(20)))).detach();
+    ///
+    ///     // The rest of the program runs while the strip is animated.
+    /// }
+    /// ```
+    pub fn animate<A: LedAnimation>(self, animation: A) -> AddrLedAnimationFuture<A> {
+        AddrLedAnimationFuture {
+            frame_buf: vec![Rgb::new(0, 0, 0); self.buf.len()],
+            strip: self,
+            animation,
+            frame: 0,
+            next_frame: Instant::now(),
+        }
+    }
+}
+
+/// An animation that can be played on an [`AdiAddrLed`] strip with [`AdiAddrLed::animate`].
+pub trait LedAnimation {
+    /// Draws frame number `frame` of the animation into `strip`, which has one color per diode.
+    ///
+    /// Frames are numbered from zero when the animation starts. `strip` holds the colors drawn
+    /// by the previous frame.
+    fn tick(&mut self, strip: &mut [Rgb<u8>], frame: u64);
+
+    /// Returns how long each frame of the animation is displayed for.
+    fn frame_interval(&self) -> Duration;
+}
+
+/// Future that plays an animation on an LED strip
+/// created with [`AdiAddrLed::animate`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct AddrLedAnimationFuture<A: LedAnimation> {
+    strip: AdiAddrLed,
+    animation: A,
+    frame_buf: Vec<Rgb<u8>>,
+    frame: u64,
+    next_frame: Instant,
+}
+
+// The animation is never structurally pinned, so it is fine to move out of a pinned future.
+impl<A: LedAnimation> Unpin for AddrLedAnimationFuture<A> {}
+
+impl<A: LedAnimation> Future for AddrLedAnimationFuture<A> {
+    type Output = AddrLedError;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let now = Instant::now();
+
+        if now >= this.next_frame {
+            this.animation.tick(&mut this.frame_buf, this.frame);
+
+            if let Err(err) = this.strip.set_buffer(this.frame_buf.iter().copied()) {
+                return Poll::Ready(err);
+            }
+
+            this.frame += 1;
+            this.next_frame += this.animation.frame_interval();
+
+            // If we fell behind, skip the missed frames rather than drawing them all at once.
+            if this.next_frame < now {
+                this.next_frame = now + this.animation.frame_interval();
+            }
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// An animation that scrolls a rainbow along the strip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RainbowCycle {
+    interval: Duration,
+}
+
+impl RainbowCycle {
+    /// Creates a new rainbow animation that shifts each diode's hue by one step every
+    /// `interval`. A full cycle through all colors takes 256 frames.
+    #[must_use]
+    pub const fn new(interval: Duration) -> Self {
+        Self { interval }
+    }
+}
+
+impl LedAnimation for RainbowCycle {
+    fn tick(&mut self, strip: &mut [Rgb<u8>], frame: u64) {
+        let length = strip.len();
+
+        for (i, pixel) in strip.iter_mut().enumerate() {
+            // Spread one full rainbow across the length of the strip.
+            let offset = (i * 256 / length) as u64;
+            *pixel = color_wheel(((frame + offset) % 256) as u8);
+        }
+    }
+
+    fn frame_interval(&self) -> Duration {
+        self.interval
+    }
+}
+
+/// Returns a fully saturated color at `position` around the color wheel, going from red to
+/// green to blue and back to red.
+const fn color_wheel(position: u8) -> Rgb<u8> {
+    match position {
+        0..=84 => Rgb::new(255 - position * 3, position * 3, 0),
+        85..=169 => {
+            let position = position - 85;
+            Rgb::new(0, 255 - position * 3, position * 3)
+        }
+        _ => {
+            let position = position - 170;
+            Rgb::new(position * 3, 0, 255 - position * 3)
+        }
+    }
+}
+
+/// An animation that smoothly fades the whole strip in and out of a single color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Breathing {
+    color: Rgb<u8>,
+    period: Duration,
+}
+
+impl Breathing {
+    /// The time between frames of the animation.
+    pub const FRAME_INTERVAL: Duration = Duration::from_millis(20);
+
+    /// Creates a new breathing animation that fades from off to `color` and back to off once
+    /// every `period`.
+    #[must_use]
+    pub fn new(color: impl Into<Rgb<u8>>, period: Duration) -> Self {
+        Self {
+            color: color.into(),
+            period,
+        }
+    }
+}
+
+impl LedAnimation for Breathing {
+    fn tick(&mut self, strip: &mut [Rgb<u8>], frame: u64) {
+        #[allow(clippy::cast_precision_loss)]
+        let elapsed = frame as f64 * Self::FRAME_INTERVAL.as_secs_f64();
+        let period = self.period.as_secs_f64();
+
+        // A cosine wave starting at zero brightness, peaking halfway through the period.
+        let brightness = if period > 0.0 {
+            (1.0 - (TAU * elapsed / period).cos()) / 2.0
+        } else {
+            1.0
+        };
+
+        let color = Rgb::new(
+            (f64::from(self.color.r) * brightness) as u8,
+            (f64::from(self.color.g) * brightness) as u8,
+            (f64::from(self.color.b) * brightness) as u8,
+        );
+        strip.fill(color);
+    }
+
+    fn frame_interval(&self) -> Duration {
+        Self::FRAME_INTERVAL
+    }
+}
+
+/// An animation that moves a single lit diode along the strip, wrapping back around to the
+/// start once it reaches the end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorChase {
+    color: Rgb<u8>,
+    interval: Duration,
+}
+
+impl ColorChase {
+    /// Creates a new chase animation that moves a diode lit with `color` forward by one diode
+    /// every `interval`.
+    #[must_use]
+    pub fn new(color: impl Into<Rgb<u8>>, interval: Duration) -> Self {
+        Self {
+            color: color.into(),
+            interval,
+        }
+    }
+}
+
+impl LedAnimation for ColorChase {
+    fn tick(&mut self, strip: &mut [Rgb<u8>], frame: u64) {
+        if strip.is_empty() {
+            return;
+        }
+
+        let lit = (frame % strip.len() as u64) as usize;
+
+        strip.fill(Rgb::new(0, 0, 0));
+        strip[lit] = self.color;
+    }
+
+    fn frame_interval(&self) -> Duration {
+        self.interval
+    }
 }
 
 impl AdiDevice<1> for AdiAddrLed {
@@ -8,11 +8,20 @@
 //! Analog-to-Digital Converter (ADC) in the V5 Brain. The Brain measures analog input
 //! using 12-bit values ranging from 0 (0V) to 4095 (5V).
 //!
+//! # Oversampling
+//!
+//! Readings from the Brain's ADC can be fairly noisy. To reduce this noise, [`AdiAnalogIn`] can
+//! average several consecutive samples into a single reading using [`AdiAnalogIn::read_averaged`].
+//! Since ADI ports are only updated every [`ADI_UPDATE_INTERVAL`], each additional sample adds 10ms
+//! of latency to the reading. Oversampling is therefore a tradeoff between noise and
+//! responsiveness, and is best suited for slow-changing signals.
+//!
 //! [digital ADI devices]: super::digital
+//! [`ADI_UPDATE_INTERVAL`]: super::ADI_UPDATE_INTERVAL
 
 use vex_sdk::vexDeviceAdiValueGet;
 
-use super::{AdiDevice, AdiDeviceType, AdiPort, PortError};
+use super::{AdiAverageFuture, AdiDevice, AdiDeviceType, AdiPort, PortError};
 
 /// The maximum 12-bit analog value returned by the internal
 /// analog-to-digital converters on the Brain.
@@ -38,8 +47,45 @@ impl AdiAnalogIn {
         Self { port }
     }
 
+    /// Reads `samples` consecutive values from the analog input channel, returning their average
+    /// as a 12-bit value (0-4095).
+    ///
+    /// A value of `0` is treated as `1`.
+    ///
+    /// # Latency
+    ///
+    /// ADI ports are only updated every [`ADI_UPDATE_INTERVAL`](super::ADI_UPDATE_INTERVAL), so
+    /// the returned future waits for a new update between each sample, taking around
+    /// `(samples - 1) * 10ms` to complete. More samples reduce noise at the cost of higher latency.
+    ///
+    /// # Errors
+    ///
+    /// - A [`PortError::Disconnected`] error is returned if an ADI expander device was required but not connected.
+    /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
+    ///   something else was connected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let sensor = AdiAnalogIn::new(peripherals.adi_a);
+    ///
+    ///     // Average 4 samples, taking around 30ms.
+    ///     println!("Averaged value: {}", sensor.read_averaged(4).await.unwrap());
+    /// }
+    /// ```
+    pub fn read_averaged(&self, samples: u8) -> AdiAverageFuture<'_> {
+        AdiAverageFuture::new(&self.port, u16::from(samples))
+    }
+
     /// Reads an analog input channel, returning the 12-bit value (0-4095).
     ///
+    /// This returns a single sample. Use [`AdiAnalogIn::read_averaged`] to reduce noise by
+    /// averaging several consecutive samples.
+    ///
     /// # Sensor Compatibility
     ///
     /// The value returned is undefined if the analog pin has been switched to a different mode.
@@ -15,9 +15,16 @@
 //! to either 3.3V (high) or 0V (low). These ports can be used with both direct brain connections
 //! and through an ADI expander module.
 
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
 use vex_sdk::{vexDeviceAdiValueGet, vexDeviceAdiValueSet};
+use vexide_core::time::Instant;
 
-use super::{AdiDevice, AdiDeviceType, AdiPort, PortError};
+use super::{AdiDevice, AdiDeviceType, AdiPort, PortError, ADI_UPDATE_INTERVAL};
 
 /// Logic level of a digital pin.
 ///
@@ -118,7 +125,147 @@ impl AdiDigitalIn {
     /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
     ///   something else was connected.
     pub fn is_low(&self) -> Result<bool, PortError> {
-        Ok(self.level()?.is_high())
+        Ok(self.level()?.is_low())
+    }
+
+    /// Waits for the input to transition from [`LogicLevel::Low`] to [`LogicLevel::High`].
+    ///
+    /// The input is sampled once every [`ADI_UPDATE_INTERVAL`], which is the rate at which
+    /// VEXos updates ADI devices. If the input is already high when this is called, the
+    /// returned future will not resolve until the input goes low and then high again.
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to an error if sampling the input fails:
+    ///
+    /// - A [`PortError::Disconnected`] error is returned if an ADI expander device was required but not connected.
+    /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
+    ///   something else was connected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let button = AdiDigitalIn::new(peripherals.adi_a);
+    ///
+    ///     println!("Press the button to start autonomous.");
+    ///     button.wait_for_press().await.unwrap();
+    ///     println!("Starting!");
+    /// }
+    /// ```
+    pub fn wait_for_press(&self) -> AdiDigitalInWaitFuture<'_> {
+        AdiDigitalInWaitFuture::new(self, Some(LogicLevel::High))
+    }
+
+    /// Waits for the input to transition from [`LogicLevel::High`] to [`LogicLevel::Low`].
+    ///
+    /// The input is sampled once every [`ADI_UPDATE_INTERVAL`], which is the rate at which
+    /// VEXos updates ADI devices. If the input is already low when this is called, the
+    /// returned future will not resolve until the input goes high and then low again.
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to an error if sampling the input fails:
+    ///
+    /// - A [`PortError::Disconnected`] error is returned if an ADI expander device was required but not connected.
+    /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
+    ///   something else was connected.
+    pub fn wait_for_release(&self) -> AdiDigitalInWaitFuture<'_> {
+        AdiDigitalInWaitFuture::new(self, Some(LogicLevel::Low))
+    }
+
+    /// Waits for the input to change logic level in either direction, resolving to the new level.
+    ///
+    /// The input is sampled once every [`ADI_UPDATE_INTERVAL`], which is the rate at which
+    /// VEXos updates ADI devices.
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to an error if sampling the input fails:
+    ///
+    /// - A [`PortError::Disconnected`] error is returned if an ADI expander device was required but not connected.
+    /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
+    ///   something else was connected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let switch = AdiDigitalIn::new(peripherals.adi_a);
+    ///
+    ///     loop {
+    ///         let level = switch.wait_for_toggle().await.unwrap();
+    ///         println!("Switch is now {level:?}");
+    ///     }
+    /// }
+    /// ```
+    pub fn wait_for_toggle(&self) -> AdiDigitalInWaitFuture<'_> {
+        AdiDigitalInWaitFuture::new(self, None)
+    }
+}
+
+/// A future that resolves when an [`AdiDigitalIn`] changes logic level.
+///
+/// This future is returned by [`AdiDigitalIn::wait_for_press`], [`AdiDigitalIn::wait_for_release`],
+/// and [`AdiDigitalIn::wait_for_toggle`], and resolves to the new logic level of the input.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AdiDigitalInWaitFuture<'a> {
+    input: &'a AdiDigitalIn,
+    /// The level to wait for, or `None` if any change should resolve the future.
+    target: Option<LogicLevel>,
+    previous_level: Option<LogicLevel>,
+    last_sample: Option<Instant>,
+}
+
+impl<'a> AdiDigitalInWaitFuture<'a> {
+    const fn new(input: &'a AdiDigitalIn, target: Option<LogicLevel>) -> Self {
+        Self {
+            input,
+            target,
+            previous_level: None,
+            last_sample: None,
+        }
+    }
+}
+
+impl Future for AdiDigitalInWaitFuture<'_> {
+    type Output = Result<LogicLevel, PortError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        // Only sample once per ADI update, since the value can't change any faster than that.
+        if this
+            .last_sample
+            .is_none_or(|last_sample| last_sample.elapsed() >= ADI_UPDATE_INTERVAL)
+        {
+            let level = match this.input.level() {
+                Ok(level) => level,
+                Err(err) => return Poll::Ready(Err(err)),
+            };
+            this.last_sample = Some(Instant::now());
+
+            if let Some(previous_level) = this.previous_level.replace(level) {
+                let transitioned = match this.target {
+                    Some(target) => previous_level == !target && level == target,
+                    None => previous_level != level,
+                };
+
+                if transitioned {
+                    return Poll::Ready(Ok(level));
+                }
+            }
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
     }
 }
 
@@ -157,7 +304,7 @@ impl AdiDigitalOut {
     ///
     /// #[vexide::main]
     /// async fn main(peripherals: Peripherals) {
-    ///     let digital_out = AdiDigitalOut::new(peripherals.adi_a);
+    ///     let mut digital_out = AdiDigitalOut::new(peripherals.adi_a);
     ///
     ///     // Toggle the digital output every second
     ///     loop {
@@ -218,7 +365,7 @@ impl AdiDigitalOut {
     ///
     /// #[vexide::main]
     /// async fn main(peripherals: Peripherals) {
-    ///     let digital_out = AdiDigitalOut::new(peripherals.adi_a);
+    ///     let mut digital_out = AdiDigitalOut::new(peripherals.adi_a);
     ///
     ///     // Set the digital output to high
     ///     _ = digital_out.set_level(LogicLevel::High);
@@ -292,7 +439,7 @@ impl AdiDigitalOut {
     /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
     ///   something else was connected.
     pub fn is_low(&self) -> Result<bool, PortError> {
-        Ok(self.level()?.is_high())
+        Ok(self.level()?.is_low())
     }
 
     /// Set the digital logic level to [`LogicLevel::High`]. Analogous to
@@ -311,7 +458,7 @@ impl AdiDigitalOut {
     ///
     /// #[vexide::main]
     /// async fn main(peripherals: Peripherals) {
-    ///     let digital_out = AdiDigitalOut::new(peripherals.adi_a);
+    ///     let mut digital_out = AdiDigitalOut::new(peripherals.adi_a);
     ///
     ///     // Set the digital output to high
     ///     _ = digital_out.set_high();
@@ -340,13 +487,13 @@ impl AdiDigitalOut {
     ///
     /// #[vexide::main]
     /// async fn main(peripherals: Peripherals) {
-    ///     let digital_out = AdiDigitalOut::new(peripherals.adi_a);
+    ///     let mut digital_out = AdiDigitalOut::new(peripherals.adi_a);
     ///
     ///     // Set the digital output to low
     ///     _ = digital_out.set_low();
     ///
     ///     // Let's check if the universe isn't broken
-    ///     assert_eq!(digital_out.level().expect("couldn't get level"), LogicLevel::High);
+    ///     assert_eq!(digital_out.level().expect("couldn't get level"), LogicLevel::Low);
     /// }
     /// ```
     pub fn set_low(&mut self) -> Result<(), PortError> {
@@ -359,6 +506,7 @@ impl AdiDigitalOut {
     /// - If the port was previously set to [`LogicLevel::High`], then the level will be set to [`LogicLevel::Low`].
     ///
     /// This is analogous to `self.set_level(!self.level()?)?` and is useful for toggling devices like solenoids.
+    /// The new logic level of the pin is returned.
     ///
     /// # Errors
     ///
@@ -366,13 +514,15 @@ impl AdiDigitalOut {
     /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
     ///   something else was connected.
     ///
+    /// # Examples
+    ///
     /// ```
     /// use vexide::prelude::*;
     /// use core::time::Duration;
     ///
     /// #[vexide::main]
     /// async fn main(peripherals: Peripherals) {
-    ///     let digital_out = AdiDigitalOut::new(peripherals.adi_a);
+    ///     let mut digital_out = AdiDigitalOut::new(peripherals.adi_a);
     ///
     ///     // Toggle the digital output every second
     ///     loop {
@@ -381,8 +531,11 @@ impl AdiDigitalOut {
     ///     }
     /// }
     /// ```
-    pub fn toggle(&mut self) -> Result<(), PortError> {
-        self.set_level(!self.level()?)
+    pub fn toggle(&mut self) -> Result<LogicLevel, PortError> {
+        let level = !self.level()?;
+        self.set_level(level)?;
+
+        Ok(level)
     }
 }
 
@@ -24,7 +24,8 @@
 //! while the other wire must be plugged into the port directly above that wire (that is, B, D, F, or
 //! H, respectively). If the top wire is plugged into the lower odd-numbered port (A, C, E, G), then
 //! *clockwise* rotation will represent a positive change in position. If the bottom wire is plugged into
-//! the lower port, then *counterclockwise* rotation will be positive instead.
+//! the lower port, then *counterclockwise* rotation will be positive instead. The direction can
+//! also be flipped in software using [`AdiEncoder::set_reversed`].
 //!
 //! # Comparison to [`RotationSensor`]
 //!
@@ -58,6 +59,7 @@ use crate::{adi::adi_port_name, position::Position, PortError};
 pub struct AdiEncoder {
     top_port: AdiPort,
     bottom_port: AdiPort,
+    reversed: bool,
 }
 
 impl AdiEncoder {
@@ -126,12 +128,13 @@ impl AdiEncoder {
         Self {
             top_port,
             bottom_port,
+            reversed: false,
         }
     }
 
-    /// Returns the distance reading of the encoder sensor in centimeters.
+    /// Returns the position of the encoder.
     ///
-    /// Round and/or fluffy objects can cause inaccurate values to be returned.
+    /// If the encoder is [reversed](AdiEncoder::set_reversed), the sign of this position is flipped.
     ///
     /// # Errors
     ///
@@ -162,7 +165,7 @@ impl AdiEncoder {
         self.top_port.validate_expander()?;
         self.top_port.configure(self.device_type());
 
-        Ok(Position::from_ticks(
+        let position = Position::from_ticks(
             unsafe {
                 i64::from(vexDeviceAdiValueGet(
                     self.top_port.device_handle(),
@@ -170,7 +173,9 @@ impl AdiEncoder {
                 ))
             },
             360,
-        ))
+        );
+
+        Ok(if self.reversed { -position } else { position })
     }
 
     /// Sets the current encoder position to the given position without any actual movement.
@@ -198,12 +203,14 @@ impl AdiEncoder {
     ///     let encoder = AdiEncoder::new(peripherals.adi_a, peripherals.adi_b);
     ///
     ///     // Treat the encoder as if it were at 180 degrees.
-    ///     _ = encoder.set_position(Position::from_degrees(180));
+    ///     _ = encoder.set_position(Position::from_degrees(180.0));
     /// }
     /// ```
     pub fn set_position(&self, position: Position) -> Result<(), PortError> {
         self.top_port.validate_expander()?;
 
+        let position = if self.reversed { -position } else { position };
+
         unsafe {
             vexDeviceAdiValueSet(
                 self.top_port.device_handle(),
@@ -218,7 +225,8 @@ impl AdiEncoder {
     /// Sets the current encoder position to zero.
     ///
     /// Analogous to taring or resetting the encoder so that the new position is equal
-    /// to the given position.
+    /// to zero. This is useful for homing routines, where a mechanism is driven to a known
+    /// position (such as a hard stop) before the encoder is zeroed.
     ///
     /// # Errors
     ///
@@ -237,16 +245,49 @@ impl AdiEncoder {
     ///
     /// #[vexide::main]
     /// async fn main(peripherals: Peripherals) {
-    ///     let encoder = AdiEncoder::new(peripherals.adi_a, peripherals.adi_b);
+    ///     let mut encoder = AdiEncoder::new(peripherals.adi_a, peripherals.adi_b);
     ///
     ///     // Reset the encoder position to zero.
     ///     // This doesn't really do anything in this case, but it's a good example.
     ///     _ = encoder.reset_position();
     /// }
     /// ```
+    #[doc(alias = "reset")]
     pub fn reset_position(&mut self) -> Result<(), PortError> {
         self.set_position(Position::default())
     }
+
+    /// Sets whether the encoder's direction is reversed in software.
+    ///
+    /// When reversed, positions returned by [`AdiEncoder::position`] and set by
+    /// [`AdiEncoder::set_position`] have their sign flipped. This has the same effect as swapping
+    /// the top and bottom wires of the encoder. Changing this setting does not reset the encoder,
+    /// so the current position will flip signs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut encoder = AdiEncoder::new(peripherals.adi_a, peripherals.adi_b);
+    ///
+    ///     // Treat counterclockwise rotation as positive.
+    ///     encoder.set_reversed(true);
+    /// }
+    /// ```
+    pub const fn set_reversed(&mut self, reversed: bool) {
+        self.reversed = reversed;
+    }
+
+    /// Returns `true` if the encoder's direction is reversed in software.
+    ///
+    /// See [`AdiEncoder::set_reversed`] for more information.
+    #[must_use]
+    pub const fn is_reversed(&self) -> bool {
+        self.reversed
+    }
 }
 
 impl AdiDevice<2> for AdiEncoder {
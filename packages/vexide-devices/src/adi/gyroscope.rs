@@ -11,10 +11,17 @@
 //!
 //! The gyroscope is rated for a noise density of 0.016 dps/√Hz (degrees per second per square root of Hertz).
 //! This means that we cannot determine the exact amount of noise in the sensor's readings because it is unknown how often VEXos polls the gyroscope.
+//!
+//! # Sensitivity
+//!
+//! Due to manufacturing variation, the yaw reported by individual gyroscopes may be slightly
+//! larger or smaller than the true rotation. This can be corrected by rotating the robot a known
+//! amount (such as ten full turns), comparing it against the reported yaw, and passing the ratio
+//! to [`AdiGyroscope::set_multiplier`].
 
 use core::{future::Future, task::Poll, time::Duration};
 
-use snafu::Snafu;
+use snafu::{ensure, Snafu};
 use vex_sdk::{vexDeviceAdiValueGet, vexDeviceAdiValueSet};
 
 use super::{AdiDevice, AdiDeviceType, AdiPort};
@@ -88,12 +95,19 @@ impl Future for AdiGyroscopeCalibrationFuture<'_> {
 }
 
 /// An ADI gyroscope.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, PartialEq)]
 pub struct AdiGyroscope {
     port: AdiPort,
+    multiplier: f64,
 }
 
 impl AdiGyroscope {
+    /// The smallest multiplier accepted by [`AdiGyroscope::set_multiplier`].
+    pub const MIN_MULTIPLIER: f64 = 0.5;
+
+    /// The largest multiplier accepted by [`AdiGyroscope::set_multiplier`].
+    pub const MAX_MULTIPLIER: f64 = 2.0;
+
     /// Create a new gyroscope on the given [`AdiPort`].
     ///
     /// # Examples
@@ -113,7 +127,10 @@ impl AdiGyroscope {
     pub fn new(port: AdiPort) -> Self {
         port.configure(AdiDeviceType::Gyro);
 
-        Self { port }
+        Self {
+            port,
+            multiplier: 1.0,
+        }
     }
 
     /// Returns true if the gyroscope is still calibrating.
@@ -168,8 +185,60 @@ impl AdiGyroscope {
         }
     }
 
+    /// Sets the sensitivity multiplier of the gyroscope.
+    ///
+    /// Yaw readings returned by [`AdiGyroscope::yaw`] are scaled by this value to correct for
+    /// variation in sensitivity between individual sensors. VEXos has no built-in multiplier
+    /// setting, so the multiplier is applied in software and takes effect on the next reading.
+    /// It is not affected by calibration, but should be set before the robot starts moving so
+    /// that all readings are scaled consistently. The default multiplier is `1.0`.
+    ///
+    /// # Errors
+    ///
+    /// - An [`AdiGyroscopeError::InvalidMultiplier`] error is returned if `multiplier` is not within
+    ///   the range [`AdiGyroscope::MIN_MULTIPLIER`] to [`AdiGyroscope::MAX_MULTIPLIER`]. Values
+    ///   outside this range are more likely to indicate a faulty measurement or sensor than a
+    ///   difference in sensitivity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut gyro = AdiGyroscope::new(peripherals.adi_port_a());
+    ///
+    ///     // This gyro reports 3560 degrees after ten full turns.
+    ///     _ = gyro.set_multiplier(3600.0 / 3560.0);
+    ///
+    ///     _ = gyro.calibrate(Duration::from_secs(2)).await;
+    ///     println!("Yaw: {:?}", gyro.yaw());
+    /// }
+    /// ```
+    pub fn set_multiplier(&mut self, multiplier: f64) -> Result<(), AdiGyroscopeError> {
+        ensure!(
+            (Self::MIN_MULTIPLIER..=Self::MAX_MULTIPLIER).contains(&multiplier),
+            InvalidMultiplierSnafu { multiplier }
+        );
+
+        self.multiplier = multiplier;
+
+        Ok(())
+    }
+
+    /// Returns the sensitivity multiplier of the gyroscope.
+    ///
+    /// See [`AdiGyroscope::set_multiplier`] for more information.
+    #[must_use]
+    pub const fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
     /// Returns the measured yaw rotation of the gyroscope.
     ///
+    /// This value is scaled by the gyroscope's [multiplier](AdiGyroscope::set_multiplier).
+    ///
     /// # Errors
     ///
     /// - A [`PortError::Disconnected`] error is returned if an ADI expander device was required but not connected.
@@ -192,7 +261,7 @@ impl AdiGyroscope {
             return Err(AdiGyroscopeError::StillCalibrating);
         }
         let value = unsafe { vexDeviceAdiValueGet(self.port.device_handle(), self.port.index()) };
-        let value = f64::from(value) / 10.0;
+        let value = f64::from(value) / 10.0 * self.multiplier;
 
         Ok(Position::from_degrees(value))
     }
@@ -223,4 +292,14 @@ pub enum AdiGyroscopeError {
     },
     /// The sensor is still calibrating.
     StillCalibrating,
+    /// The multiplier is outside of the range accepted by [`AdiGyroscope::set_multiplier`].
+    #[snafu(display(
+        "Multiplier {multiplier} is outside of the accepted range of {}-{}.",
+        AdiGyroscope::MIN_MULTIPLIER,
+        AdiGyroscope::MAX_MULTIPLIER,
+    ))]
+    InvalidMultiplier {
+        /// The rejected multiplier.
+        multiplier: f64,
+    },
 }
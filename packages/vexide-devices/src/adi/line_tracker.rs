@@ -37,15 +37,30 @@
 //! For best results when using the Line Tracking Sensors, it is best to mount the sensors
 //! between 1/8 and 1/4 of an inch away from the surface it is measuring. It is also important
 //! to keep lighting in the room consistent, so sensors' readings remain accurate.
+//!
+//! # Calibration
+//!
+//! Since ambient lighting affects the sensor's readings, the same surface may read differently
+//! from one environment to the next. [`AdiLineTracker::calibrate`] can be used to measure known
+//! white and black surfaces, after which [`AdiLineTracker::value`] will return readings
+//! normalized between the two.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
 
 use vex_sdk::vexDeviceAdiValueGet;
 
-use super::{analog, AdiDevice, AdiDeviceType, AdiPort, PortError};
+use super::{analog, AdiDevice, AdiDeviceType, AdiPort, AdiSampler, PortError};
 
 /// Line Tracker
 #[derive(Debug, Eq, PartialEq)]
 pub struct AdiLineTracker {
     port: AdiPort,
+    white_point: u16,
+    black_point: u16,
 }
 
 impl AdiLineTracker {
@@ -69,7 +84,11 @@ impl AdiLineTracker {
     pub fn new(port: AdiPort) -> Self {
         port.configure(AdiDeviceType::LineTracker);
 
-        Self { port }
+        Self {
+            port,
+            white_point: 0,
+            black_point: analog::ADC_MAX_VALUE,
+        }
     }
 
     /// Returns the reflectivity factor measured by the sensor. Higher numbers mean
@@ -134,6 +153,125 @@ impl AdiLineTracker {
 
         Ok(unsafe { vexDeviceAdiValueGet(self.port.device_handle(), self.port.index()) } as u16)
     }
+
+    /// Measures the sensor's reading over a known white or black surface, storing it as a
+    /// calibration point for [`AdiLineTracker::value`]. Returns the averaged 12-bit reading.
+    ///
+    /// The sensor should be held over the surface for the duration of the calibration. Both a
+    /// white and a black surface should be measured for [`AdiLineTracker::value`] to be fully
+    /// calibrated. Until then, the sensor's full raw range of [0, 4095] is used for the
+    /// uncalibrated point.
+    ///
+    /// # Latency
+    ///
+    /// ADI ports are only updated every [`ADI_UPDATE_INTERVAL`](super::ADI_UPDATE_INTERVAL), so
+    /// the returned future waits for a new update between each of the `samples` readings that are
+    /// averaged together, taking around `(samples - 1) * 10ms` to complete. A value of `0` is
+    /// treated as `1`.
+    ///
+    /// # Errors
+    ///
+    /// - A [`PortError::Disconnected`] error is returned if an ADI expander device was required but not connected.
+    /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
+    ///   something else was connected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut line_tracker = AdiLineTracker::new(peripherals.adi_b);
+    ///
+    ///     // Calibrate over the field tile, then over the white tape line.
+    ///     line_tracker.calibrate(false, 10).await.unwrap();
+    ///     sleep(core::time::Duration::from_secs(3)).await;
+    ///     line_tracker.calibrate(true, 10).await.unwrap();
+    ///
+    ///     loop {
+    ///         if line_tracker.value().unwrap() < 0.5 {
+    ///             println!("On the line!");
+    ///         }
+    ///         sleep(vexide::devices::adi::ADI_UPDATE_INTERVAL).await;
+    ///     }
+    /// }
+    /// ```
+    pub fn calibrate(
+        &mut self,
+        white_surface: bool,
+        samples: u16,
+    ) -> AdiLineTrackerCalibrationFuture<'_> {
+        AdiLineTrackerCalibrationFuture {
+            line_tracker: self,
+            white_surface,
+            sampler: AdiSampler::new(samples),
+        }
+    }
+
+    /// Returns the raw (white, black) calibration points set by [`AdiLineTracker::calibrate`].
+    #[must_use]
+    pub const fn calibration(&self) -> (u16, u16) {
+        (self.white_point, self.black_point)
+    }
+
+    /// Returns the sensor's reading normalized to the surfaces measured by
+    /// [`AdiLineTracker::calibrate`], where `0.0` is white and `1.0` is black.
+    ///
+    /// Readings outside of the calibrated range are clamped to [0.0, 1.0]. If the sensor has not
+    /// been calibrated, this is equivalent to `1.0 - reflectivity()`.
+    ///
+    /// # Errors
+    ///
+    /// - A [`PortError::Disconnected`] error is returned if an ADI expander device was required but not connected.
+    /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
+    ///   something else was connected.
+    pub fn value(&self) -> Result<f64, PortError> {
+        let raw = self.raw_reflectivity()?;
+
+        // The calibration points are too close together (or reversed) to interpolate between,
+        // so just report which side of the black point the reading falls on.
+        if self.black_point <= self.white_point {
+            return Ok(if raw >= self.black_point { 1.0 } else { 0.0 });
+        }
+
+        let value = f64::from(raw.clamp(self.white_point, self.black_point) - self.white_point)
+            / f64::from(self.black_point - self.white_point);
+
+        Ok(value)
+    }
+}
+
+/// A future that measures an [`AdiLineTracker`]'s reading over a white or black surface.
+///
+/// This future is returned by [`AdiLineTracker::calibrate`], and resolves to the averaged 12-bit
+/// reading that was stored as a calibration point.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AdiLineTrackerCalibrationFuture<'a> {
+    line_tracker: &'a mut AdiLineTracker,
+    white_surface: bool,
+    sampler: AdiSampler,
+}
+
+impl Future for AdiLineTrackerCalibrationFuture<'_> {
+    type Output = Result<u16, PortError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        this.sampler
+            .poll_average(&this.line_tracker.port, cx)
+            .map_ok(|average| {
+                if this.white_surface {
+                    this.line_tracker.white_point = average;
+                } else {
+                    this.line_tracker.black_point = average;
+                }
+
+                average
+            })
+    }
 }
 
 impl AdiDevice<1> for AdiLineTracker {
@@ -24,7 +24,12 @@
 //! [`AdiExpander`]: crate::smart::expander::AdiExpander
 //! [Cortex microcontroller]: <https://www.vexrobotics.com/276-2194.html>
 
-use core::time::Duration;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
 
 use crate::PortError;
 
@@ -55,9 +60,10 @@ pub use pwm::AdiPwmOut;
 pub use range_finder::AdiRangeFinder;
 pub use servo::AdiServo;
 use vex_sdk::{
-    vexDeviceAdiPortConfigGet, vexDeviceAdiPortConfigSet, vexDeviceGetByIndex,
-    V5_AdiPortConfiguration, V5_DeviceT,
+    vexDeviceAdiPortConfigGet, vexDeviceAdiPortConfigSet, vexDeviceAdiValueGet,
+    vexDeviceGetByIndex, V5_AdiPortConfiguration, V5_DeviceT,
 };
+use vexide_core::time::Instant;
 
 use crate::smart::{validate_port, SmartDeviceType};
 
@@ -193,6 +199,96 @@ impl From<AdiEncoder> for (AdiPort, AdiPort) {
     }
 }
 
+/// A future that averages consecutive 12-bit readings from an analog ADI port.
+///
+/// A new sample is taken every [`ADI_UPDATE_INTERVAL`], since the Brain only reads a new value
+/// from the port at that rate. The future resolves to the average of all samples, rounded to the
+/// nearest value.
+///
+/// This future is returned by [`AdiAnalogIn::read_averaged`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AdiAverageFuture<'a> {
+    port: &'a AdiPort,
+    sampler: AdiSampler,
+}
+
+impl<'a> AdiAverageFuture<'a> {
+    /// Creates a future that averages `samples` readings from `port`. A `samples` count of `0`
+    /// is treated as `1`.
+    pub(crate) fn new(port: &'a AdiPort, samples: u16) -> Self {
+        Self {
+            port,
+            sampler: AdiSampler::new(samples),
+        }
+    }
+}
+
+impl Future for AdiAverageFuture<'_> {
+    type Output = Result<u16, PortError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        this.sampler.poll_average(this.port, cx)
+    }
+}
+
+/// Averages consecutive 12-bit readings from an analog ADI port, taking one sample per ADI update.
+///
+/// This holds the sampling state for [`AdiAverageFuture`] and for device futures that need an
+/// averaged reading while also borrowing the device, such as calibration futures.
+#[derive(Debug)]
+pub(crate) struct AdiSampler {
+    samples: u16,
+    taken: u16,
+    sum: u32,
+    last_sample: Option<Instant>,
+}
+
+impl AdiSampler {
+    /// Creates a sampler that averages `samples` readings. A `samples` count of `0` is treated
+    /// as `1`.
+    pub(crate) fn new(samples: u16) -> Self {
+        Self {
+            samples: samples.max(1),
+            taken: 0,
+            sum: 0,
+            last_sample: None,
+        }
+    }
+
+    /// Takes a sample from `port` if the Brain has read a new value since the last sample,
+    /// resolving to the average of all samples once they have been taken.
+    pub(crate) fn poll_average(
+        &mut self,
+        port: &AdiPort,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<u16, PortError>> {
+        if self
+            .last_sample
+            .is_none_or(|last_sample| last_sample.elapsed() >= ADI_UPDATE_INTERVAL)
+        {
+            if let Err(err) = port.validate_expander() {
+                return Poll::Ready(Err(err));
+            }
+
+            let value = unsafe { vexDeviceAdiValueGet(port.device_handle(), port.index()) };
+            self.sum += u32::from(value as u16);
+            self.taken += 1;
+            self.last_sample = Some(Instant::now());
+
+            if self.taken == self.samples {
+                let samples = u32::from(self.samples);
+                // Round to the nearest value.
+                return Poll::Ready(Ok(((self.sum + samples / 2) / samples) as u16));
+            }
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
 /// Common functionality for a ADI (three-wire) devices.
 pub trait AdiDevice<const N: usize> {
     /// Update rate of ADI devices.
@@ -225,6 +225,64 @@ impl AdiMotor {
     pub fn stop(&mut self) -> Result<(), PortError> {
         self.set_raw_output(0)
     }
+
+    /// Returns a rough estimate of the current drawn by the motor in amps.
+    ///
+    /// ADI motors provide no current sensing, so this is calculated purely from the last set
+    /// [output](AdiMotor::output) by linearly interpolating between the motor's free-running
+    /// current and its stall current:
+    ///
+    /// ```text
+    /// current = free_current + |output| * (stall_current - free_current)
+    /// ```
+    ///
+    /// A stopped motor is assumed to draw no current. Both currents should be taken from the
+    /// motor's specifications at the supply voltage being used. For reference, the 393 motor
+    /// is rated for a free current of 0.37A and a stall current of 4.8A at 7.2V.
+    ///
+    /// # Accuracy
+    ///
+    /// This is only an estimate. The actual current depends on the load on the motor rather than
+    /// just its output, so a lightly loaded motor will draw less than this function reports. This
+    /// makes the estimate most suitable for conservative power budgeting across multiple motors.
+    ///
+    /// # Errors
+    ///
+    /// - A [`PortError::Disconnected`] error is returned if an ADI expander device was required but not connected.
+    /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
+    ///   something else was connected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut motor = AdiMotor::new(peripherals.adi_a, true);
+    ///     _ = motor.set_output(0.5);
+    ///
+    ///     // Estimate the current draw of a 393 motor.
+    ///     if let Ok(current) = motor.estimated_current_draw(0.37, 4.8) {
+    ///         println!("Estimated current: {current:.2}A");
+    ///     }
+    /// }
+    /// ```
+    pub fn estimated_current_draw(
+        &self,
+        free_current: f64,
+        stall_current: f64,
+    ) -> Result<f64, PortError> {
+        let raw_output = self.raw_output()?;
+
+        if raw_output == 0 {
+            return Ok(0.0);
+        }
+
+        let output = f64::from(raw_output.unsigned_abs()) / f64::from(i8::MAX);
+
+        Ok(free_current + output * (stall_current - free_current))
+    }
 }
 
 impl AdiDevice<1> for AdiMotor {
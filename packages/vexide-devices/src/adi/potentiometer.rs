@@ -43,10 +43,12 @@ use super::{analog, AdiDevice, AdiDeviceType, AdiPort};
 use crate::PortError;
 
 /// Potentiometer
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, PartialEq)]
 pub struct AdiPotentiometer {
     potentiometer_type: PotentiometerType,
     port: AdiPort,
+    raw_bounds: (u16, u16),
+    angle_bounds: (f64, f64),
 }
 
 impl AdiPotentiometer {
@@ -77,9 +79,61 @@ impl AdiPotentiometer {
         Self {
             potentiometer_type,
             port,
+            raw_bounds: (0, analog::ADC_MAX_VALUE),
+            angle_bounds: (0.0, potentiometer_type.max_angle()),
         }
     }
 
+    /// Create a new potentiometer from an [`AdiPort`] that is calibrated to a mechanism.
+    ///
+    /// The raw ADC readings `raw_min` and `raw_max` are mapped to the angles `angle_min` and
+    /// `angle_max` (in degrees) by [`AdiPotentiometer::angle_bounded`]. This is useful when a
+    /// mechanism's hard stops don't line up with the ends of the potentiometer's travel, since
+    /// the raw readings at each stop can be measured with [`AdiPotentiometer::raw_value`] and
+    /// mapped to the mechanism's actual angles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `raw_min` is not less than `raw_max`, or if `raw_max` is greater than 4095.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     // An arm reads 610 when lowered to -15 degrees, and 2980 when raised to 90 degrees.
+    ///     let potentiometer = AdiPotentiometer::from_raw_range(
+    ///         peripherals.adi_a,
+    ///         PotentiometerType::V2,
+    ///         610,
+    ///         2980,
+    ///         -15.0,
+    ///         90.0,
+    ///     );
+    ///
+    ///     if let Ok(angle) = potentiometer.angle_bounded() {
+    ///         println!("Arm Angle: {}", angle);
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_raw_range(
+        port: AdiPort,
+        potentiometer_type: PotentiometerType,
+        raw_min: u16,
+        raw_max: u16,
+        angle_min: f64,
+        angle_max: f64,
+    ) -> Self {
+        let mut potentiometer = Self::new(port, potentiometer_type);
+        potentiometer.set_raw_bounds(raw_min, raw_max);
+        potentiometer.angle_bounds = (angle_min, angle_max);
+
+        potentiometer
+    }
+
     /// Returns the type of ADI potentiometer device.
     ///
     /// This is either the legacy EDR potentiometer or the V5-era potentiometer V2.
@@ -130,6 +184,121 @@ impl AdiPotentiometer {
                 / f64::from(analog::ADC_MAX_VALUE),
         )
     }
+
+    /// Returns the current unscaled ADC reading of the potentiometer.
+    ///
+    /// This is a 12-bit value from 0 to 4095, which increases as the shaft rotates.
+    ///
+    /// # Errors
+    ///
+    /// - A [`PortError::Disconnected`] error is returned if an ADI expander device was required but not connected.
+    /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
+    ///   something else was connected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let potentiometer = AdiPotentiometer::new(peripherals.adi_a, PotentiometerType::V2);
+    ///     loop {
+    ///         if let Ok(raw) = potentiometer.raw_value() {
+    ///             println!("Raw Reading: {}", raw);
+    ///         }
+    ///         sleep(Duration::from_millis(10)).await;
+    ///     }
+    /// }
+    /// ```
+    pub fn raw_value(&self) -> Result<u16, PortError> {
+        self.port.validate_expander()?;
+
+        Ok(unsafe { vexDeviceAdiValueGet(self.port.device_handle(), self.port.index()) } as u16)
+    }
+
+    /// Sets the raw ADC readings that correspond to either end of the potentiometer's travel.
+    ///
+    /// Due to manufacturing tolerances and how the potentiometer is mounted, a potentiometer
+    /// may never actually report the full range of ADC values from 0 to 4095. Measuring the raw
+    /// readings at each end of a mechanism's travel and passing them to this function allows
+    /// [`AdiPotentiometer::angle_bounded`] to map exactly that range to the potentiometer's
+    /// [angle bounds](AdiPotentiometer::angle_bounds).
+    ///
+    /// By default, the bounds are `0` and `4095`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is not less than `max`, or if `max` is greater than 4095.
+    pub fn set_raw_bounds(&mut self, min: u16, max: u16) {
+        assert!(min < max, "minimum raw bound must be less than the maximum");
+        assert!(
+            max <= analog::ADC_MAX_VALUE,
+            "maximum raw bound must be at most 4095"
+        );
+
+        self.raw_bounds = (min, max);
+    }
+
+    /// Returns the raw ADC readings that correspond to either end of the potentiometer's travel.
+    ///
+    /// See [`AdiPotentiometer::set_raw_bounds`] for more information.
+    #[must_use]
+    pub const fn raw_bounds(&self) -> (u16, u16) {
+        self.raw_bounds
+    }
+
+    /// Returns the angles (in degrees) that the potentiometer's [raw bounds](AdiPotentiometer::raw_bounds)
+    /// are mapped to by [`AdiPotentiometer::angle_bounded`].
+    ///
+    /// By default, these are `0.0` and the potentiometer's [`max_angle`](AdiPotentiometer::max_angle).
+    /// Other angles can be configured with [`AdiPotentiometer::from_raw_range`].
+    #[must_use]
+    pub const fn angle_bounds(&self) -> (f64, f64) {
+        self.angle_bounds
+    }
+
+    /// Returns the current potentiometer angle in degrees, scaled from the potentiometer's
+    /// [raw bounds](AdiPotentiometer::set_raw_bounds) to its [angle bounds](AdiPotentiometer::angle_bounds).
+    ///
+    /// Unlike [`AdiPotentiometer::angle`], readings outside of the configured raw bounds are clamped,
+    /// so the returned angle is always between the angle bounds (0 and
+    /// [`max_angle`](AdiPotentiometer::max_angle) degrees by default).
+    ///
+    /// # Errors
+    ///
+    /// - A [`PortError::Disconnected`] error is returned if an ADI expander device was required but not connected.
+    /// - A [`PortError::IncorrectDevice`] error is returned if an ADI expander device was required but
+    ///   something else was connected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut potentiometer = AdiPotentiometer::new(peripherals.adi_a, PotentiometerType::V2);
+    ///
+    ///     // This potentiometer was measured to read from 20 to 4060 across its travel.
+    ///     potentiometer.set_raw_bounds(20, 4060);
+    ///
+    ///     if let Ok(angle) = potentiometer.angle_bounded() {
+    ///         println!("Potentiometer Angle: {}", angle);
+    ///     }
+    /// }
+    /// ```
+    pub fn angle_bounded(&self) -> Result<f64, PortError> {
+        self.port.validate_expander()?;
+
+        let (min, max) = self.raw_bounds;
+        let (angle_min, angle_max) = self.angle_bounds;
+        let raw = unsafe { vexDeviceAdiValueGet(self.port.device_handle(), self.port.index()) }
+            .clamp(i32::from(min), i32::from(max));
+
+        Ok(angle_min
+            + f64::from(raw - i32::from(min)) * (angle_max - angle_min) / f64::from(max - min))
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -24,8 +24,22 @@
 //! - Maximum: 50 degrees (represented by [`AdiServo::MAX_POSITION`])
 //!
 //! Its neutral state is at 0° rotation (the middle of its operating range).
+//!
+//! # Feedback
+//!
+//! Unlike smart motors, servos do not report their position back to the brain. There is no way
+//! to know when a servo has actually reached its target. [`AdiServo::move_to_position_async`]
+//! provides a best-effort wait based on an estimate of how long the movement takes.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
 
 use vex_sdk::vexDeviceAdiValueSet;
+use vexide_core::time::Instant;
 
 use super::{AdiDevice, AdiDeviceType, AdiPort};
 use crate::{position::Position, PortError};
@@ -51,6 +65,14 @@ impl AdiServo {
 
     /// Create a servo from an [`AdiPort`].
     ///
+    /// # Timing
+    ///
+    /// Servos provide no position feedback, so the time taken to reach a target must be
+    /// estimated. This depends on the distance traveled and the load on the servo, so it
+    /// should be measured on the robot by timing a full sweep from [`AdiServo::MIN_POSITION`]
+    /// to [`AdiServo::MAX_POSITION`] and scaling it by the fraction of the range being traveled.
+    /// This estimate can then be passed to [`AdiServo::move_to_position_async`].
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -136,6 +158,109 @@ impl AdiServo {
 
         Ok(())
     }
+
+    /// Sets the servo's position target, then waits for an estimated amount of time for the servo
+    /// to reach it.
+    ///
+    /// The target is set when the returned future is first polled, and the future completes once
+    /// `estimated_duration` has elapsed. This is a time-based estimate, not a feedback-based one, since servos do not
+    /// report their position. If the servo is stalled or slowed by a load, it may not have reached
+    /// its target by the time the future completes. See [`AdiServo::new`] for advice on choosing
+    /// a duration.
+    ///
+    /// # Errors
+    ///
+    /// The returned future will resolve to:
+    ///
+    /// - A [`PortError::Disconnected`] error if an ADI expander device was required but not connected.
+    /// - A [`PortError::IncorrectDevice`] error if an ADI expander device was required but
+    ///   something else was connected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vexide::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut servo = AdiServo::new(peripherals.adi_a);
+    ///
+    ///     // Move to 25 degrees, which takes roughly 200ms on our robot.
+    ///     _ = servo
+    ///         .move_to_position_async(Position::from_degrees(25.0), Duration::from_millis(200))
+    ///         .await;
+    ///
+    ///     println!("Servo should have reached its target.");
+    /// }
+    /// ```
+    pub fn move_to_position_async(
+        &mut self,
+        position: Position,
+        estimated_duration: Duration,
+    ) -> AdiServoMoveFuture<'_> {
+        AdiServoMoveFuture {
+            servo: self,
+            state: AdiServoMoveState::SetTarget {
+                position,
+                estimated_duration,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum AdiServoMoveState {
+    /// Set the servo's target, then wait for the estimated duration.
+    SetTarget {
+        position: Position,
+        estimated_duration: Duration,
+    },
+    /// Wait until the servo is estimated to have reached its target.
+    Waiting(Instant),
+}
+
+/// A future that waits for an [`AdiServo`] to reach its target.
+///
+/// This future is returned by [`AdiServo::move_to_position_async`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct AdiServoMoveFuture<'a> {
+    // Held to prevent the target from being changed while waiting.
+    servo: &'a mut AdiServo,
+    state: AdiServoMoveState,
+}
+
+impl Future for AdiServoMoveFuture<'_> {
+    type Output = Result<(), PortError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        let deadline = match this.state {
+            // The target is only set once the future is first polled.
+            AdiServoMoveState::SetTarget {
+                position,
+                estimated_duration,
+            } => {
+                if let Err(err) = this.servo.set_target(position) {
+                    return Poll::Ready(Err(err));
+                }
+
+                let deadline = Instant::now() + estimated_duration;
+                this.state = AdiServoMoveState::Waiting(deadline);
+                deadline
+            }
+            AdiServoMoveState::Waiting(deadline) => deadline,
+        };
+
+        if Instant::now() >= deadline {
+            return Poll::Ready(Ok(()));
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
 }
 
 impl AdiDevice<1> for AdiServo {
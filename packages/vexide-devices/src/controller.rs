@@ -13,7 +13,7 @@ use vex_sdk::{
     vexControllerConnectionStatusGet, vexControllerGet, vexControllerTextSet, V5_ControllerId,
     V5_ControllerIndex, V5_ControllerStatus,
 };
-use vexide_core::competition::{self, CompetitionMode};
+use vexide_core::competition::{self, CompetitionMode, CueController};
 
 /// Represents the state of a button on the controller.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
@@ -157,6 +157,24 @@ fn validate_connection(id: ControllerId) -> Result<(), ControllerError> {
     Ok(())
 }
 
+/// Attempts to send a rumble pattern to a controller's vibration motor, failing if the
+/// controller is busy.
+fn try_rumble(id: ControllerId, pattern: &str) -> Result<(), ControllerError> {
+    validate_connection(id)?;
+
+    let id: V5_ControllerId = id.into();
+    let pattern = CString::new(pattern)
+        .expect("A NUL (0x00) character was found in the rumble pattern string.");
+
+    // Rumble patterns are sent by writing to line 4, the line after the last visible one. This
+    // is the same line used by `Controller::rumble`.
+    if unsafe { vexControllerTextSet(u32::from(id.0), 4, 0, pattern.as_ptr().cast()) } != 1 {
+        return WriteBusySnafu.fail();
+    }
+
+    Ok(())
+}
+
 enum ControllerScreenWriteFutureState<'a> {
     /// Waiting for the controller to be ready to accept a new write.
     WaitingForIdle {
@@ -588,6 +606,18 @@ impl From<ControllerId> for V5_ControllerId {
     }
 }
 
+/// Plays [`PhaseCues`](competition::PhaseCues) on the controller with this ID using
+/// [`Controller::try_rumble`].
+///
+/// This allows cues to be played on a controller that is also being used by the rest of the
+/// program, since the [`CompetitionRuntime`](competition::CompetitionRuntime) only needs the
+/// controller's ID rather than the [`Controller`] itself.
+impl CueController for ControllerId {
+    fn try_play_cue(&mut self, pattern: &str) -> bool {
+        try_rumble(*self, pattern).is_ok()
+    }
+}
+
 /// Represents the state of a controller's connection.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ControllerConnection {
@@ -976,7 +1006,7 @@ impl Controller {
     /// }
     /// ```
     pub fn try_rumble(&mut self, pattern: impl AsRef<str>) -> Result<(), ControllerError> {
-        self.screen.try_set_text(pattern, 3, 0)
+        try_rumble(self.id, pattern.as_ref())
     }
 }
 
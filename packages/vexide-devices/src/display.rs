@@ -5,9 +5,17 @@
 //! and the [`Stroke`] trait can be used to draw the outlines of shapes.
 
 use alloc::{ffi::CString, string::String, vec::Vec};
-use core::{ffi::CStr, mem, ptr::addr_of_mut, time::Duration};
+use core::{
+    ffi::CStr,
+    future::Future,
+    mem,
+    pin::Pin,
+    ptr::addr_of_mut,
+    task::{Context, Poll},
+    time::Duration,
+};
 
-use snafu::{ensure, Snafu};
+use snafu::{ensure, OptionExt, Snafu};
 use vex_sdk::{
     vexDisplayBackgroundColor, vexDisplayCircleDraw, vexDisplayCircleFill, vexDisplayCopyRect,
     vexDisplayErase, vexDisplayFontNamedSet, vexDisplayForegroundColor, vexDisplayLineDraw,
@@ -15,7 +23,7 @@ use vex_sdk::{
     vexDisplayScrollRect, vexDisplayString, vexDisplayStringHeightGet, vexDisplayStringWidthGet,
     vexDisplayTextSize, vexTouchDataGet, V5_TouchEvent, V5_TouchStatus,
 };
-use vexide_core::float::Float;
+use vexide_core::{float::Float, fs, io, path::Path};
 
 use crate::{
     math::Point2,
@@ -252,6 +260,123 @@ impl Fill for Rect {
     }
 }
 
+/// A rectangle with rounded corners that can be drawn on the display.
+///
+/// Like [`Rect`], both the start and end points of the rectangle are included inside the
+/// drawn region. The corner radius is limited to half of the rectangle's width or height,
+/// whichever is smaller, so a square with a large enough radius is drawn as a circle.
+///
+/// # Example
+///
+/// ```
+/// use vexide::prelude::*;
+/// use vexide::devices::display::{Rect, RoundedRect};
+///
+/// #[vexide::main]
+/// async fn main(peripherals: Peripherals) {
+///     let mut display = peripherals.display;
+///
+///     // Draw a button with a light outline.
+///     let button = RoundedRect::new(Rect::new([20, 20], [180, 70]), 10);
+///     display.fill(&button, Rgb::new(40, 40, 60));
+///     display.stroke(&button, Rgb::new(200, 200, 255));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RoundedRect {
+    /// The bounds of the rectangle.
+    pub rect: Rect,
+
+    /// Radius of the rectangle's corners.
+    pub radius: u16,
+}
+
+impl RoundedRect {
+    /// Create a new rounded rectangle with the given bounds and corner radius.
+    #[must_use]
+    pub const fn new(rect: Rect, radius: u16) -> Self {
+        Self { rect, radius }
+    }
+
+    /// Returns the left, top, right, and bottom edges of the rectangle in screen coordinates,
+    /// followed by the corner radius clamped to fit inside the rectangle.
+    fn edges(&self) -> (i32, i32, i32, i32, i32) {
+        let (start, end) = (self.rect.start, self.rect.end);
+
+        let left = i32::from(start.x.min(end.x));
+        let right = i32::from(start.x.max(end.x));
+        let top = i32::from(start.y.min(end.y) + Display::HEADER_HEIGHT);
+        let bottom = i32::from(start.y.max(end.y) + Display::HEADER_HEIGHT);
+
+        let radius = i32::from(self.radius)
+            .min((right - left) / 2)
+            .min((bottom - top) / 2);
+
+        (left, top, right, bottom, radius)
+    }
+}
+
+impl Fill for RoundedRect {
+    fn fill(&self, _display: &mut Display, color: impl Into<Rgb<u8>>) {
+        let (left, top, right, bottom, radius) = self.edges();
+
+        unsafe {
+            vexDisplayForegroundColor(color.into().into_raw());
+
+            // Fill everything except the corners with two overlapping rectangles, then round
+            // off the corners with a circle at each one.
+            vexDisplayRectFill(left + radius, top, right - radius, bottom);
+            vexDisplayRectFill(left, top + radius, right, bottom - radius);
+
+            for (x, y) in [
+                (left + radius, top + radius),
+                (right - radius, top + radius),
+                (left + radius, bottom - radius),
+                (right - radius, bottom - radius),
+            ] {
+                vexDisplayCircleFill(x, y, radius);
+            }
+        }
+    }
+}
+
+impl Stroke for RoundedRect {
+    fn stroke(&self, _display: &mut Display, color: impl Into<Rgb<u8>>) {
+        let (left, top, right, bottom, radius) = self.edges();
+
+        unsafe {
+            vexDisplayForegroundColor(color.into().into_raw());
+
+            vexDisplayLineDraw(left + radius, top, right - radius, top);
+            vexDisplayLineDraw(left + radius, bottom, right - radius, bottom);
+            vexDisplayLineDraw(left, top + radius, left, bottom - radius);
+            vexDisplayLineDraw(right, top + radius, right, bottom - radius);
+
+            // VEXos can only draw full circles, so the corner arcs are plotted one pixel at a
+            // time using the midpoint circle algorithm.
+            let (mut x, mut y) = (radius, 0);
+            let mut error = 1 - radius;
+
+            while x >= y {
+                for (dx, dy) in [(x, y), (y, x)] {
+                    vexDisplayPixelSet((left + radius - dx) as _, (top + radius - dy) as _);
+                    vexDisplayPixelSet((right - radius + dx) as _, (top + radius - dy) as _);
+                    vexDisplayPixelSet((left + radius - dx) as _, (bottom - radius + dy) as _);
+                    vexDisplayPixelSet((right - radius + dx) as _, (bottom - radius + dy) as _);
+                }
+
+                y += 1;
+                if error < 0 {
+                    error += 2 * y + 1;
+                } else {
+                    x -= 1;
+                    error += 2 * (y - x) + 1;
+                }
+            }
+        }
+    }
+}
+
 /// Options for how a text object should be formatted.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct Font {
@@ -268,6 +393,36 @@ impl Font {
         Self { size, family }
     }
 
+    /// Returns the width of a single character in this font in pixels.
+    ///
+    /// For [`FontFamily::Monospace`] fonts, every character has this width, so the width of a
+    /// string is this value multiplied by its length. [`FontFamily::Proportional`] fonts have a
+    /// different width for each character, and this returns the width of the digit `0`. Use
+    /// [`Text::width`] to measure a specific string in a proportional font.
+    ///
+    /// Font metrics depend on the [`FontSize`], and are measured from the fonts built into VEXos.
+    #[must_use]
+    pub fn char_width(self) -> u16 {
+        unsafe {
+            self.apply();
+            vexDisplayStringWidthGet(c"0".as_ptr()) as _
+        }
+    }
+
+    /// Returns the height of a single line of text in this font in pixels.
+    ///
+    /// This is the vertical distance that should be left between the top of each line when
+    /// drawing multiple lines of [`Text`].
+    ///
+    /// Font metrics depend on the [`FontSize`], and are measured from the fonts built into VEXos.
+    #[must_use]
+    pub fn line_height(self) -> u16 {
+        unsafe {
+            self.apply();
+            vexDisplayStringHeightGet(c"0".as_ptr()) as _
+        }
+    }
+
     /// Set the display's font to this font.
     fn apply(self) {
         unsafe {
@@ -597,6 +752,131 @@ impl From<V5_TouchEvent> for TouchState {
     }
 }
 
+/// A future that resolves when the display is next pressed.
+///
+/// This future is returned by [`Display::wait_for_touch`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForTouchFuture<'a> {
+    display: &'a Display,
+    press_count: i32,
+}
+
+impl Future for WaitForTouchFuture<'_> {
+    type Output = TouchEvent;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let event = self.display.touch_status();
+
+        if event.press_count == self.press_count {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        } else {
+            Poll::Ready(event)
+        }
+    }
+}
+
+/// An image file format that can be detected by [`ImageFormat::detect`].
+///
+/// See [`Display::draw_image_file`] for which formats can be drawn to the display.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ImageFormat {
+    /// Windows Bitmap (`.bmp`) image.
+    Bmp,
+    /// Portable Network Graphics (`.png`) image.
+    Png,
+}
+
+impl ImageFormat {
+    const BMP_MAGIC: &'static [u8] = b"BM";
+    const PNG_MAGIC: &'static [u8] = b"\x89PNG\r\n\x1a\n";
+
+    /// Detects the format of an image from the magic bytes at the start of its data,
+    /// returning `None` if the format is not recognized.
+    #[must_use]
+    pub fn detect(data: &[u8]) -> Option<Self> {
+        if data.starts_with(Self::PNG_MAGIC) {
+            Some(Self::Png)
+        } else if data.starts_with(Self::BMP_MAGIC) {
+            Some(Self::Bmp)
+        } else {
+            None
+        }
+    }
+}
+
+/// Decodes an uncompressed 24-bit or 32-bit BMP image, returning its dimensions and pixels in
+/// row-major order starting from the top-left corner.
+fn decode_bmp(data: &[u8]) -> Result<(u16, u16, Vec<Rgb<u8>>), ImageError> {
+    let read_u16 = |offset: usize| {
+        data.get(offset..offset + 2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+    };
+    let read_u32 = |offset: usize| {
+        data.get(offset..offset + 4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    };
+
+    let pixel_offset = read_u32(10).context(CorruptImageSnafu)? as usize;
+    let width = read_u32(18).context(CorruptImageSnafu)? as i32;
+    // A negative height indicates that rows are stored top-to-bottom rather than bottom-to-top.
+    let height = read_u32(22).context(CorruptImageSnafu)? as i32;
+    let bits_per_pixel = read_u16(28).context(CorruptImageSnafu)?;
+    let compression = read_u32(30).context(CorruptImageSnafu)?;
+
+    ensure!(width > 0 && height != 0, CorruptImageSnafu);
+    ensure!(
+        compression == 0,
+        UnsupportedFormatSnafu {
+            format: "compressed BMP"
+        }
+    );
+    ensure!(
+        matches!(bits_per_pixel, 24 | 32),
+        UnsupportedFormatSnafu {
+            format: "BMP with a bit depth other than 24 or 32"
+        }
+    );
+
+    let top_down = height < 0;
+    let width = width.unsigned_abs() as usize;
+    let height = height.unsigned_abs() as usize;
+
+    ensure!(
+        width <= Display::HORIZONTAL_RESOLUTION as usize
+            && height <= Display::VERTICAL_RESOLUTION as usize,
+        ImageTooLargeSnafu { width, height }
+    );
+
+    let bytes_per_pixel = usize::from(bits_per_pixel / 8);
+    // Each row is padded to a multiple of four bytes.
+    let row_size = (width * bytes_per_pixel).div_ceil(4) * 4;
+
+    // The pixel offset comes straight from the file, so it may point far past the end of it.
+    let pixel_data_end = row_size
+        .checked_mul(height)
+        .and_then(|size| pixel_offset.checked_add(size))
+        .context(CorruptImageSnafu)?;
+    let pixel_data = data
+        .get(pixel_offset..pixel_data_end)
+        .context(CorruptImageSnafu)?;
+
+    let mut pixels = Vec::with_capacity(width * height);
+    for y in 0..height {
+        let row_index = if top_down { y } else { height - 1 - y };
+        let row = &pixel_data[row_index * row_size..][..width * bytes_per_pixel];
+
+        // Pixels are stored in BGR(A) order.
+        pixels.extend(
+            row.chunks_exact(bytes_per_pixel)
+                .map(|pixel| Rgb::new(pixel[2], pixel[1], pixel[0])),
+        );
+    }
+
+    Ok((width as u16, height as u16, pixels))
+}
+
 /// The rendering mode for the VEX V5's display
 ///
 /// When using the display in the [`Immediate`](RenderMode::Immediate) mode, all draw operations will immediately show up on the display.
@@ -802,6 +1082,71 @@ impl Display {
         }
     }
 
+    /// Loads an image from the SD card and draws it to the display with its top-left corner
+    /// at `top_left`.
+    ///
+    /// The format of the image is detected from its contents (see [`ImageFormat::detect`])
+    /// rather than from the file extension.
+    ///
+    /// # Supported Formats
+    ///
+    /// - [`ImageFormat::Bmp`]: Uncompressed 24-bit and 32-bit bitmaps, stored either
+    ///   bottom-up or top-down. Alpha channels are ignored.
+    ///
+    /// PNG images are detected, but are not currently supported and will return an
+    /// [`ImageError::UnsupportedFormat`] error.
+    ///
+    /// # Memory Usage
+    ///
+    /// The entire file is read into memory before being decoded. Decoding then requires an
+    /// additional 3 bytes per pixel, and another 4 bytes per pixel while the image is copied
+    /// to the display. As an example, drawing a full-screen 480x240 24-bit bitmap temporarily
+    /// uses about 1.1MiB of heap. All of this memory is freed before this function returns.
+    ///
+    /// # Errors
+    ///
+    /// - An [`ImageError::Io`] error is returned if the file could not be read.
+    /// - An [`ImageError::UnknownFormat`] error is returned if the file is not a recognized image format.
+    /// - An [`ImageError::UnsupportedFormat`] error is returned if the image is in a recognized
+    ///   format or variant of a format that can't be decoded.
+    /// - An [`ImageError::ImageTooLarge`] error is returned if the image is larger than the display.
+    /// - An [`ImageError::CorruptImage`] error is returned if the image's headers are invalid or the
+    ///   file is truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut display = peripherals.display;
+    ///
+    ///     display.draw_image_file("logo.bmp", [0, 0]).unwrap();
+    /// }
+    /// ```
+    pub fn draw_image_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        top_left: impl Into<Point2<i16>>,
+    ) -> Result<(), ImageError> {
+        let data = fs::read(path).map_err(|error| ImageError::Io { error })?;
+
+        let (width, height, pixels) = match ImageFormat::detect(&data) {
+            Some(ImageFormat::Bmp) => decode_bmp(&data)?,
+            Some(ImageFormat::Png) => return UnsupportedFormatSnafu { format: "PNG" }.fail(),
+            None => return UnknownFormatSnafu.fail(),
+        };
+
+        self.draw_buffer(
+            Rect::from_dimensions(top_left, width, height),
+            pixels,
+            i32::from(width),
+        );
+
+        Ok(())
+    }
+
     /// Returns the current touch status of the display.
     #[must_use]
     pub fn touch_status(&self) -> TouchEvent {
@@ -820,6 +1165,33 @@ impl Display {
             release_count: touch_status.releaseCount,
         }
     }
+
+    /// Waits until the display is pressed, returning the [`TouchEvent`] of that press.
+    ///
+    /// Only presses that occur after this function is called will resolve the returned
+    /// future. A touch that is already being held when this is called will not be reported
+    /// until it is released and the display is pressed again.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let display = peripherals.display;
+    ///
+    ///     // Wait for the user to tap the screen before continuing.
+    ///     let touch = display.wait_for_touch().await;
+    ///     println!("Display pressed at ({}, {})", touch.x, touch.y);
+    /// }
+    /// ```
+    pub fn wait_for_touch(&self) -> WaitForTouchFuture<'_> {
+        WaitForTouchFuture {
+            display: self,
+            press_count: self.touch_status().press_count,
+        }
+    }
 }
 
 /// An error that occurs when a negative or non-finite font size is attempted to be created.
@@ -829,3 +1201,80 @@ pub struct InvalidFontSizeError {
     /// The negative value that was attempted to be used as a font size.
     pub value: f32,
 }
+
+/// Errors that can occur when drawing an image file with [`Display::draw_image_file`].
+#[derive(Debug, Snafu)]
+pub enum ImageError {
+    /// The image file could not be read from the SD card.
+    #[snafu(display("Failed to read image file: {error}"))]
+    Io {
+        /// The underlying I/O error.
+        error: io::Error,
+    },
+
+    /// The file's contents do not match any known image format.
+    UnknownFormat,
+
+    /// The image is in a format that cannot be decoded.
+    #[snafu(display("Unsupported image format: {format}"))]
+    UnsupportedFormat {
+        /// A description of the unsupported format.
+        format: &'static str,
+    },
+
+    /// The image is larger than the display.
+    #[snafu(display("Image of size {width}x{height} is larger than the display."))]
+    ImageTooLarge {
+        /// The width of the image in pixels.
+        width: usize,
+        /// The height of the image in pixels.
+        height: usize,
+    },
+
+    /// The image's headers are invalid or its data is truncated.
+    CorruptImage,
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{decode_bmp, ImageError};
+    use crate::rgb::Rgb;
+
+    /// Builds a 24-bit BMP file with the given pixel data offset and a single-pixel image.
+    fn bmp(pixel_offset: u32) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(b"BM");
+        data.extend_from_slice(&58u32.to_le_bytes()); // file size
+        data.extend_from_slice(&[0; 4]); // reserved
+        data.extend_from_slice(&pixel_offset.to_le_bytes());
+        data.extend_from_slice(&40u32.to_le_bytes()); // info header size
+        data.extend_from_slice(&1i32.to_le_bytes()); // width
+        data.extend_from_slice(&1i32.to_le_bytes()); // height
+        data.extend_from_slice(&1u16.to_le_bytes()); // planes
+        data.extend_from_slice(&24u16.to_le_bytes()); // bits per pixel
+        data.extend_from_slice(&[0; 24]); // compression and the rest of the info header
+        data.extend_from_slice(&[0x30, 0x20, 0x10, 0x00]); // one BGR pixel and row padding
+        data
+    }
+
+    #[test]
+    fn decode_bmp_pixel() {
+        let (width, height, pixels) = decode_bmp(&bmp(54)).unwrap();
+        assert_eq!((width, height), (1, 1));
+        assert_eq!(pixels, [Rgb::new(0x10, 0x20, 0x30)]);
+    }
+
+    #[test]
+    fn decode_bmp_huge_pixel_offset() {
+        assert!(matches!(
+            decode_bmp(&bmp(u32::MAX)),
+            Err(ImageError::CorruptImage)
+        ));
+        assert!(matches!(
+            decode_bmp(&bmp(u32::MAX - 2)),
+            Err(ImageError::CorruptImage)
+        ));
+    }
+}
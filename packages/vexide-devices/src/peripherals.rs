@@ -81,14 +81,40 @@
 //! bookkeeping at runtime rather than compile time. This trades a small performance cost
 //! for increased flexibility, but is generally preferable to use the static [`Peripherals`]
 //! struct at runtime.
+//!
+//! # Validating Robot Configuration
+//!
+//! The [`scan`] function returns the type of device currently plugged into each Smart Port. Building
+//! on this, a [`PeripheralsBuilder`] can be used to declare which devices your robot *expects* to be
+//! plugged into each port, and check that the physical robot matches at startup. This catches wiring
+//! mistakes (such as swapped or unplugged cables) before a match begins:
+//!
+//! ```
+//! use vexide::prelude::*;
+//! use vexide::devices::{peripherals::{MismatchPolicy, PeripheralsBuilder}, smart::SmartDeviceType};
+//!
+//! const ROBOT_CONFIG: PeripheralsBuilder = PeripheralsBuilder::new()
+//!     .expect_device(1, SmartDeviceType::Motor)
+//!     .expect_device(2, SmartDeviceType::Motor)
+//!     .expect_device(10, SmartDeviceType::Imu)
+//!     .on_mismatch(MismatchPolicy::Warn);
+//!
+//! #[vexide::main]
+//! async fn main(peripherals: Peripherals) {
+//!     let peripherals = ROBOT_CONFIG.build(peripherals).unwrap();
+//! }
+//! ```
 
 use core::sync::atomic::AtomicBool;
 
+use vex_sdk::V5_MAX_DEVICE_PORTS;
+
 use crate::{
     adi::AdiPort,
     controller::{Controller, ControllerId},
     display::Display,
-    smart::SmartPort,
+    smart::{connected_device_types, SmartDeviceType, SmartPort},
+    DisconnectedSnafu, IncorrectDeviceSnafu, PortError,
 };
 
 static PERIPHERALS_TAKEN: AtomicBool = AtomicBool::new(false);
@@ -350,6 +376,16 @@ impl DynamicPeripherals {
         self.smart_ports[port_index] = Some(port);
     }
 
+    /// Returns `true` if the [`SmartPort`] with the given number has not been taken.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the provided port is outside the range 1-21.
+    #[must_use]
+    pub const fn is_smart_port_available(&self, port_number: u8) -> bool {
+        self.smart_ports[port_number as usize - 1].is_some()
+    }
+
     /// Creates an [`AdiPort`] only if one has not been created on the given slot before.
     ///
     /// # Panics
@@ -370,6 +406,16 @@ impl DynamicPeripherals {
         self.adi_slots[port_number] = Some(port);
     }
 
+    /// Returns `true` if the [`AdiPort`] with the given number has not been taken.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the provided port is outside the range 1-8.
+    #[must_use]
+    pub const fn is_adi_port_available(&self, port_number: u8) -> bool {
+        self.adi_slots[port_number as usize - 1].is_some()
+    }
+
     /// Creates a [`Display`] only if one has not been created before.
     pub fn take_display(&mut self) -> Option<Display> {
         self.display.take()
@@ -402,3 +448,161 @@ impl From<Peripherals> for DynamicPeripherals {
         Self::new(peripherals)
     }
 }
+
+/// Returns the type of device currently plugged into each Smart Port.
+///
+/// The returned array is indexed by port number minus one, so the device on port 1 is at
+/// index `0`. Ports with no device plugged in are `None`.
+///
+/// Unlike [`SmartPort::device_type`], this does not require ownership of any ports, and
+/// reads the status of every port at once.
+///
+/// # Examples
+///
+/// ```
+/// use vexide::devices::peripherals::scan;
+///
+/// for (index, device_type) in scan().iter().enumerate() {
+///     if let Some(device_type) = device_type {
+///         println!("Port {}: {:?}", index + 1, device_type);
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn scan() -> [Option<SmartDeviceType>; V5_MAX_DEVICE_PORTS] {
+    connected_device_types()
+}
+
+/// Determines how [`PeripheralsBuilder::build`] handles devices that do not match the declared
+/// robot configuration.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MismatchPolicy {
+    /// Return the first mismatch as an error, refusing to hand out the peripherals.
+    #[default]
+    Deny,
+
+    /// Print every mismatch to stdout, then continue as normal.
+    Warn,
+
+    /// Panic with the first mismatch.
+    Panic,
+}
+
+/// A declaration of the devices that are expected to be plugged into each Smart Port.
+///
+/// A builder is created with [`PeripheralsBuilder::new`], after which the expected device type
+/// on each port is declared using [`PeripheralsBuilder::expect_device`]. Ports that are not
+/// declared are not checked, so any device (or no device) may be plugged into them. Since every
+/// method on this type is `const`, a robot's configuration can be declared once as a `const` item.
+///
+/// Calling [`PeripheralsBuilder::build`] compares the declaration with the output of [`scan`].
+/// A port is considered mismatched if it has no device plugged into it, or if a device of a
+/// different type is plugged into it. Mismatches are reported as [`PortError`]s, and are handled
+/// according to the builder's [`MismatchPolicy`] (by default, [`MismatchPolicy::Deny`]).
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeripheralsBuilder {
+    expected: [Option<SmartDeviceType>; V5_MAX_DEVICE_PORTS],
+    policy: MismatchPolicy,
+}
+
+impl PeripheralsBuilder {
+    /// Creates a new builder with no expected devices.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            expected: [None; V5_MAX_DEVICE_PORTS],
+            policy: MismatchPolicy::Deny,
+        }
+    }
+
+    /// Declares that a device of type `device_type` should be plugged into the given port.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the provided port is outside the range 1-21.
+    #[must_use]
+    pub const fn expect_device(mut self, port_number: u8, device_type: SmartDeviceType) -> Self {
+        assert!(
+            matches!(port_number, 1..=21),
+            "Smart Port numbers must be in the range 1-21"
+        );
+        self.expected[port_number as usize - 1] = Some(device_type);
+        self
+    }
+
+    /// Sets how mismatched devices are handled by [`PeripheralsBuilder::build`].
+    #[must_use]
+    pub const fn on_mismatch(mut self, policy: MismatchPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns an iterator over every port whose connected device does not match the declared
+    /// configuration.
+    pub fn mismatches(&self) -> impl Iterator<Item = PortError> {
+        let connected = scan();
+
+        self.expected
+            .into_iter()
+            .zip(connected)
+            .zip(1u8..)
+            .filter_map(|((expected, actual), port)| {
+                let expected = expected?;
+
+                match actual {
+                    None => Some(DisconnectedSnafu { port }.build()),
+                    Some(actual) if actual != expected => Some(
+                        IncorrectDeviceSnafu {
+                            expected,
+                            actual,
+                            port,
+                        }
+                        .build(),
+                    ),
+                    Some(_) => None,
+                }
+            })
+    }
+
+    /// Validates the connected devices against the declared configuration, returning the
+    /// peripherals if the configuration was accepted.
+    ///
+    /// # Errors
+    ///
+    /// - If the policy is [`MismatchPolicy::Deny`], the first mismatched port is returned as a
+    ///   [`PortError`], along with the peripherals so that they are not lost. The peripherals
+    ///   can only be taken once, so this allows a program to handle the mismatch and keep running.
+    ///
+    /// # Panics
+    ///
+    /// - If the policy is [`MismatchPolicy::Panic`], this function panics if any port is mismatched.
+    pub fn build(self, peripherals: Peripherals) -> Result<Peripherals, (Peripherals, PortError)> {
+        match self.policy {
+            MismatchPolicy::Deny => {
+                if let Some(mismatch) = self.mismatches().next() {
+                    return Err((peripherals, mismatch));
+                }
+            }
+            MismatchPolicy::Warn => {
+                for mismatch in self.mismatches() {
+                    vexide_core::println!("Warning: {mismatch}");
+                }
+            }
+            MismatchPolicy::Panic => {
+                if let Some(mismatch) = self.mismatches().next() {
+                    panic!("Robot configuration mismatch: {mismatch}");
+                }
+            }
+        }
+
+        Ok(peripherals)
+    }
+}
+
+impl Default for PeripheralsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
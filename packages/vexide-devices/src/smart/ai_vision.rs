@@ -129,6 +129,44 @@ pub enum AiVisionObject {
     },
 }
 
+impl AiVisionObject {
+    /// Returns the ID of the signature, code, tag, or model class that this object was
+    /// detected as.
+    const fn id(&self) -> u8 {
+        match self {
+            Self::Color { id, .. }
+            | Self::Code { id, .. }
+            | Self::AprilTag { id, .. }
+            | Self::Model { id, .. } => *id,
+        }
+    }
+
+    /// Returns the area of the object in the camera's image, in square pixels.
+    fn apparent_area(&self) -> u32 {
+        match self {
+            Self::Color { width, height, .. }
+            | Self::Code { width, height, .. }
+            | Self::Model { width, height, .. } => u32::from(*width) * u32::from(*height),
+            Self::AprilTag {
+                top_left,
+                top_right,
+                bottom_right,
+                bottom_left,
+            } => {
+                // Shoelace formula for the area of the quadrilateral formed by the tag's corners.
+                let corners = [top_left, top_right, bottom_right, bottom_left];
+                let mut doubled_area = 0i32;
+                for (i, a) in corners.iter().enumerate() {
+                    let b = corners[(i + 1) % corners.len()];
+                    doubled_area +=
+                        i32::from(a.x) * i32::from(b.y) - i32::from(b.x) * i32::from(a.y);
+                }
+                doubled_area.unsigned_abs() / 2
+            }
+        }
+    }
+}
+
 /// Possible april tag families to be detected by the sensor.
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
 #[repr(u8)]
@@ -189,6 +227,25 @@ impl From<AiVisionDetectionMode> for AiVisionFlags {
     }
 }
 
+impl From<AiVisionFlags> for AiVisionDetectionMode {
+    fn from(value: AiVisionFlags) -> Self {
+        let disabled = value
+            & (AiVisionFlags::DISABLE_APRILTAG
+                | AiVisionFlags::DISABLE_COLOR
+                | AiVisionFlags::DISABLE_MODEL);
+
+        // Detection modes share bit positions with their corresponding disable flags.
+        let mut mode = Self::from_bits_truncate(!disabled.bits())
+            & (Self::APRILTAG | Self::COLOR | Self::MODEL);
+        mode.set(
+            Self::COLOR_MERGE,
+            value.contains(AiVisionFlags::COLOR_MERGE),
+        );
+
+        mode
+    }
+}
+
 /// A color signature used by an AI Vision Sensor to detect color blobs.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct AiVisionColor {
@@ -731,6 +788,37 @@ impl AiVisionSensor {
         self.set_flags(flags)
     }
 
+    /// Returns the current detection mode of the AI Vision sensor.
+    ///
+    /// This can be used alongside [`Self::set_detection_mode`] to temporarily switch modes during
+    /// a match (for example, using color detection during autonomous and AprilTag detection for
+    /// positioning) and later restore the previous mode.
+    ///
+    /// # Errors
+    ///
+    /// - A [`PortError`] is returned if an AI Vision is not connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut ai_vision = AiVisionSensor::new(peripherals.port_1);
+    ///
+    ///     let previous_mode = ai_vision.detection_mode().unwrap();
+    ///     _ = ai_vision.set_detection_mode(AiVisionDetectionMode::COLOR | AiVisionDetectionMode::APRILTAG);
+    ///
+    ///     // ...
+    ///
+    ///     _ = ai_vision.set_detection_mode(previous_mode);
+    /// }
+    /// ```
+    pub fn detection_mode(&self) -> Result<AiVisionDetectionMode> {
+        Ok(self.flags()?.into())
+    }
+
     fn raw_status(&self) -> Result<u32> {
         self.validate_port()?;
         let status = unsafe { vexDeviceAiVisionStatusGet(self.device) };
@@ -971,6 +1059,47 @@ impl AiVisionSensor {
         Ok(objects)
     }
 
+    /// Returns the nearest object detected by the AI Vision sensor, or `None` if no matching
+    /// objects were detected.
+    ///
+    /// If `id` is `Some`, only objects with that ID (such as a model's class ID, or the ID of a
+    /// color signature, color code, or AprilTag) are considered.
+    ///
+    /// The sensor does not measure the distance to objects, so the nearest object is estimated to be
+    /// the one that appears largest in the camera's image. This works best when comparing objects
+    /// of the same physical size, such as game elements of a single type.
+    ///
+    /// # Errors
+    ///
+    /// - A [`PortError`] is returned if an AI Vision is not connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// Print the horizontal center of the nearest object with a class ID of 1:
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let ai_vision = AiVisionSensor::new(peripherals.port_1);
+    ///     loop {
+    ///         if let Ok(Some(AiVisionObject::Model { position, width, .. })) =
+    ///             ai_vision.nearest_object(Some(1))
+    ///         {
+    ///             println!("Target center x: {}", position.x + width / 2);
+    ///         }
+    ///         sleep(AiVisionSensor::UPDATE_INTERVAL).await;
+    ///     }
+    /// }
+    /// ```
+    pub fn nearest_object(&self, id: Option<u8>) -> Result<Option<AiVisionObject>> {
+        Ok(self
+            .objects()?
+            .into_iter()
+            .filter(|object| id.is_none_or(|id| object.id() == id))
+            .max_by_key(AiVisionObject::apparent_area))
+    }
+
     /// Returns the number of objects currently detected by the AI Vision sensor.
     ///
     /// # Errors
@@ -97,7 +97,7 @@ impl DistanceSensor {
     ///     let sensor = DistanceSensor::new(peripherals.port_1);
     ///
     ///     if let Some(object) = sensor.object().unwrap_or_default() {
-    ///         println!("Object of size {}mm is moving at {}m/s", object.distance, object.velocity);
+    ///         println!("Object {}mm away is moving at {}m/s", object.distance, object.velocity);
     ///     }
     /// }
     /// ```
@@ -140,6 +140,90 @@ impl DistanceSensor {
         }
     }
 
+    /// Returns the relative velocity of the detected object in m/s, or `None` if no object
+    /// could be found.
+    ///
+    /// This is the same value as [`DistanceObject::velocity`], but avoids reading the other
+    /// object measurements when only velocity is needed. Velocity is measured along the sensor's
+    /// beam, so only motion directly towards or away from the sensor is reported. Combined with
+    /// the object's distance, this can be used to estimate how soon the robot will reach the
+    /// object and slow down accordingly.
+    ///
+    /// # Errors
+    ///
+    /// - A [`DistanceError::Port`] error is returned if there is not a distance sensor connected to the port.
+    /// - A [`DistanceError::StillInitializing`] error is returned if the distance sensor is still initializing.
+    /// - A [`DistanceError::BadStatusCode`] error is returned if the distance sensor has an unknown status code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let sensor = DistanceSensor::new(peripherals.port_1);
+    ///
+    ///     if let Ok(Some(velocity)) = sensor.object_velocity() {
+    ///         println!("Object is moving at {velocity}m/s");
+    ///     }
+    /// }
+    /// ```
+    pub fn object_velocity(&self) -> Result<Option<f64>, DistanceError> {
+        self.validate()?;
+
+        if unsafe { vexDeviceDistanceDistanceGet(self.device) } == 9999 {
+            return Ok(None);
+        }
+
+        Ok(Some(unsafe {
+            vexDeviceDistanceObjectVelocityGet(self.device)
+        }))
+    }
+
+    /// Returns the relative size of the detected object, or `None` if no object could be found.
+    ///
+    /// This is the same value as [`DistanceObject::relative_size`], but avoids reading the other
+    /// object measurements when only size is needed. It is a unitless value from 0 to 400 based on
+    /// the strength of the reflected signal, where an 18" x 30" grey card reads approximately 75
+    /// in typical room lighting. Since the reflected signal also depends on how far away the object
+    /// is, comparing sizes is most reliable when combined with the object's distance, such as to
+    /// tell game elements apart from field walls at a known range.
+    ///
+    /// # Errors
+    ///
+    /// - A [`DistanceError::Port`] error is returned if there is not a distance sensor connected to the port.
+    /// - A [`DistanceError::StillInitializing`] error is returned if the distance sensor is still initializing.
+    /// - A [`DistanceError::BadStatusCode`] error is returned if the distance sensor has an unknown status code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let sensor = DistanceSensor::new(peripherals.port_1);
+    ///
+    ///     if let Ok(Some(size)) = sensor.object_size() {
+    ///         if size > 200 {
+    ///             println!("Large object detected");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn object_size(&self) -> Result<Option<u32>, DistanceError> {
+        self.validate()?;
+
+        if unsafe { vexDeviceDistanceDistanceGet(self.device) } == 9999 {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            unsafe { vexDeviceDistanceObjectSizeGet(self.device) } as u32
+        ))
+    }
+
     /// Returns the internal status code of the distance sensor.
     /// The status code of the signature can tell you if the sensor is still initializing or if it is working correctly.
     /// If the distance sensor is still initializing, the status code will be 0x00.
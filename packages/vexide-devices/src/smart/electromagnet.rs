@@ -12,12 +12,18 @@
 //! Assuming that the plastic part of the disk is made of ABS plastic and the metal part is solid iron,
 //! the electromagnet can lift at least ≈0.24oz based off of the CAD model files for the V5 Workcell kit provided by VEX.
 
-use core::time::Duration;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
 
 use vex_sdk::{
     vexDeviceMagnetCurrentGet, vexDeviceMagnetPowerGet, vexDeviceMagnetPowerSet,
     vexDeviceMagnetStatusGet, vexDeviceMagnetTemperatureGet, V5_DeviceT,
 };
+use vexide_core::time::Instant;
 
 use super::{SmartDevice, SmartDeviceType, SmartPort};
 use crate::PortError;
@@ -96,6 +102,45 @@ impl Electromagnet {
         Ok(())
     }
 
+    /// Energizes the magnet at a given power level, then de-energizes it once `duration` has elapsed.
+    ///
+    /// Power is expressed as a number from [-1.0, 1.0], like in [`Electromagnet::set_power`]. The
+    /// magnet is energized when the returned future is first polled, and the future completes
+    /// after the magnet has been turned off.
+    ///
+    /// `duration` is limited to [`Electromagnet::MAX_POWER_DURATION`]. Longer durations will be
+    /// shortened to this limit.
+    ///
+    /// # Errors
+    ///
+    /// - A [`PortError::Disconnected`] error is returned if an electromagnet device was required but not connected.
+    /// - A [`PortError::IncorrectDevice`] error is returned if an electromagnet device was required but
+    ///   something else was connected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut electromagnet = Electromagnet::new(peripherals.port_1);
+    ///
+    ///     // Hold onto a disk for one second, then drop it.
+    ///     _ = electromagnet.pulse(1.0, Duration::from_secs(1)).await;
+    /// }
+    /// ```
+    pub fn pulse(&mut self, power: f64, duration: Duration) -> ElectromagnetPulseFuture<'_> {
+        ElectromagnetPulseFuture {
+            electromagnet: self,
+            state: ElectromagnetPulseState::Energize {
+                power,
+                duration: duration.min(Self::MAX_POWER_DURATION),
+            },
+        }
+    }
+
     /// Returns the user-set power level as a number from [-1.0, 1.0].
     ///
     /// # Errors
@@ -211,6 +256,53 @@ impl Electromagnet {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+enum ElectromagnetPulseState {
+    /// Energize the magnet for the given duration.
+    Energize { power: f64, duration: Duration },
+    /// Wait until the deadline passes, then de-energize the magnet.
+    Waiting(Instant),
+}
+
+/// A future that de-energizes an [`Electromagnet`] after a fixed duration.
+///
+/// This future is returned by [`Electromagnet::pulse`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct ElectromagnetPulseFuture<'a> {
+    electromagnet: &'a mut Electromagnet,
+    state: ElectromagnetPulseState,
+}
+
+impl Future for ElectromagnetPulseFuture<'_> {
+    type Output = Result<(), PortError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        let deadline = match this.state {
+            // The magnet is only energized once the future is first polled.
+            ElectromagnetPulseState::Energize { power, duration } => {
+                if let Err(err) = this.electromagnet.set_power(power, duration) {
+                    return Poll::Ready(Err(err));
+                }
+
+                let deadline = Instant::now() + duration;
+                this.state = ElectromagnetPulseState::Waiting(deadline);
+                deadline
+            }
+            ElectromagnetPulseState::Waiting(deadline) => deadline,
+        };
+
+        if Instant::now() >= deadline {
+            return Poll::Ready(this.electromagnet.set_power(0.0, Duration::ZERO));
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
 impl SmartDevice for Electromagnet {
     fn port_number(&self) -> u8 {
         self.port.number()
@@ -98,6 +98,58 @@ impl AdiExpander {
             }
         }
     }
+
+    /// Splits the expander into pairs of adjacent ports for use with two-wire devices.
+    ///
+    /// Two-wire devices such as [`AdiEncoder`] and [`AdiRangeFinder`] must be plugged into an
+    /// adjacent pair of ports (A and B, C and D, E and F, or G and H). This function returns those
+    /// four pairs in order, with the lower (odd-numbered) port first. Each pair can be passed
+    /// directly to the constructor of a two-wire device without any further validation.
+    ///
+    /// This consumes the expander, so all eight ports must be taken as pairs. To mix one-wire and
+    /// two-wire devices on the same expander, move the individual port fields out instead.
+    ///
+    /// [`AdiEncoder`]: crate::adi::AdiEncoder
+    /// [`AdiRangeFinder`]: crate::adi::AdiRangeFinder
+    ///
+    /// # Examples
+    ///
+    /// Creating two encoders and a range finder on an expander:
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let expander = AdiExpander::new(peripherals.port_1);
+    ///     let [ab, cd, ef, _gh] = expander.into_port_pairs();
+    ///
+    ///     let left_encoder = AdiEncoder::new(ab.0, ab.1);
+    ///     let right_encoder = AdiEncoder::new(cd.0, cd.1);
+    ///     let range_finder = AdiRangeFinder::new(ef.0, ef.1);
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn into_port_pairs(self) -> [(AdiPort, AdiPort); 4] {
+        let Self {
+            adi_a,
+            adi_b,
+            adi_c,
+            adi_d,
+            adi_e,
+            adi_f,
+            adi_g,
+            adi_h,
+            ..
+        } = self;
+
+        [
+            (adi_a, adi_b),
+            (adi_c, adi_d),
+            (adi_e, adi_f),
+            (adi_g, adi_h),
+        ]
+    }
 }
 
 impl SmartDevice for AdiExpander {
@@ -32,7 +32,7 @@ use vex_sdk::{
 };
 use vexide_core::float::Float;
 
-use super::{validate_port, SmartDevice, SmartDeviceType, SmartPort};
+use super::{validate_port, SmartDevice, SmartDeviceTimestamp, SmartDeviceType, SmartPort};
 use crate::{math::Point2, PortError};
 
 /// A GPS sensor plugged into a Smart Port.
@@ -43,6 +43,16 @@ pub struct GpsSensor {
 
     /// Internal IMU
     pub imu: GpsImu,
+
+    velocity_sample: Option<VelocitySample>,
+}
+
+/// The last position sample taken by [`GpsSensor::velocity`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct VelocitySample {
+    position: Point2<f64>,
+    timestamp: SmartDeviceTimestamp,
+    velocity: Option<(f64, f64)>,
 }
 
 // SAFETY: Required because we store a raw pointer to the device handle to avoid it getting from the
@@ -105,6 +115,7 @@ impl GpsSensor {
                 heading_offset: Default::default(),
             },
             port,
+            velocity_sample: None,
         }
     }
 
@@ -142,6 +153,54 @@ impl GpsSensor {
         Ok(data)
     }
 
+    /// Sets the position and heading that the sensor assumes the robot is starting from.
+    ///
+    /// This is the same initial pose passed to [`GpsSensor::new`]. The sensor uses it as a hint
+    /// until it has located itself from the field strips, so providing an accurate starting
+    /// pose lets it report useful data sooner. If the robot's starting position isn't known
+    /// when the sensor is created (for example, when it depends on an autonomous selection),
+    /// call this once it is known, before the match starts.
+    ///
+    /// The position is in meters from the center of the field, and the heading is in degrees
+    /// in the same angle system as [`GpsSensor::pose`].
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a GPS sensor is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut gps = GpsSensor::new(
+    ///         peripherals.port_1,
+    ///         [2.0, 1.0],
+    ///         ([0.0, 0.0], 90.0)
+    ///     );
+    ///
+    ///     // The selected autonomous route starts from the other side of the field.
+    ///     _ = gps.set_initial_pose([-1.5, 0.6], 180.0);
+    /// }
+    /// ```
+    pub fn set_initial_pose(
+        &mut self,
+        position: impl Into<Point2<f64>>,
+        heading: f64,
+    ) -> Result<(), PortError> {
+        self.validate_port()?;
+
+        let position = position.into();
+
+        unsafe {
+            vexDeviceGpsInitialPositionSet(self.device, position.x, position.y, 360.0 - heading);
+        }
+
+        Ok(())
+    }
+
     /// Returns the currently computed pose (heading and position) from the sensor.
     ///
     /// # Important note about heading!
@@ -207,8 +266,85 @@ impl GpsSensor {
         ))
     }
 
+    /// Estimates the velocity of the robot in meters per second along the x and y axes of the field.
+    ///
+    /// The GPS sensor does not report velocity directly, so this is computed by differentiating the
+    /// position returned by [`GpsSensor::pose`] over the sensor's internal [`GpsSensor::timestamp`].
+    ///
+    /// # Sampling
+    ///
+    /// Each call samples the sensor's position and computes the change since the previous sample, so:
+    ///
+    /// - The first call (and the first call after the sensor's clock resets due to a disconnect) only
+    ///   records a sample and returns `None`.
+    /// - Calling this more often than the sensor produces new data will return the previously computed
+    ///   value until a new reading arrives.
+    /// - The result is averaged over the time between calls, so this should be called at a regular
+    ///   interval for the value to be meaningful.
+    ///
+    /// Since GPS position readings contain some noise (see [`GpsSensor::error`]), velocities computed
+    /// over very short intervals may be noisy. Filtering the result is recommended.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a GPS sensor is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut gps = GpsSensor::new(
+    ///         peripherals.port_1,
+    ///         [2.0, 1.0],
+    ///         ([0.0, 0.0], 90.0)
+    ///     );
+    ///
+    ///     loop {
+    ///         if let Ok(Some((vx, vy))) = gps.velocity() {
+    ///             println!("Velocity: x={vx:.2}m/s, y={vy:.2}m/s");
+    ///         }
+    ///
+    ///         sleep(GpsSensor::UPDATE_INTERVAL).await;
+    ///     }
+    /// }
+    /// ```
+    pub fn velocity(&mut self) -> Result<Option<(f64, f64)>, PortError> {
+        let (position, _) = self.pose()?;
+        let timestamp = self.timestamp()?;
+
+        let velocity = match self.velocity_sample {
+            // No new data has been received from the sensor since the last sample.
+            Some(sample) if sample.timestamp == timestamp => return Ok(sample.velocity),
+            // The sensor's clock has not been reset since the last sample.
+            Some(sample) if sample.timestamp < timestamp => {
+                let elapsed_secs = f64::from(timestamp.0 - sample.timestamp.0) / 1000.0;
+                Some((
+                    (position.x - sample.position.x) / elapsed_secs,
+                    (position.y - sample.position.y) / elapsed_secs,
+                ))
+            }
+            _ => None,
+        };
+
+        self.velocity_sample = Some(VelocitySample {
+            position,
+            timestamp,
+            velocity,
+        });
+
+        Ok(velocity)
+    }
+
     /// Returns the RMS (Root Mean Squared) error for the GPS position reading in meters.
     ///
+    /// This is the sensor's own estimate of how far its reported position may be from the true
+    /// position, and is the best available indicator of whether GPS readings can be trusted. The
+    /// error grows when the sensor cannot see enough of the field code strip, such as when it is
+    /// blocked by another robot.
+    ///
     /// # Errors
     ///
     /// An error is returned if a GPS sensor is not currently connected to the Smart Port.
@@ -227,8 +363,10 @@ impl GpsSensor {
     ///     );
     ///
     ///     // Check position accuracy
-    ///     if gps.error().is_ok_and(|err| err > 0.3) {
-    ///         println!("Warning: GPS position accuracy is low ({}m error)", error);
+    ///     if let Ok(error) = gps.error() {
+    ///         if error > 0.3 {
+    ///             println!("Warning: GPS position accuracy is low ({}m error)", error);
+    ///         }
     ///     }
     /// }
     /// ```
@@ -240,6 +378,9 @@ impl GpsSensor {
 
     /// Returns the internal status code of the sensor.
     ///
+    /// The meaning of the individual bits in this code is not documented by VEX, so it is returned
+    /// as-is. To determine whether position readings are reliable, use [`GpsSensor::error`] instead.
+    ///
     /// # Errors
     ///
     /// An error is returned if a GPS sensor is not currently connected to the Smart Port.
@@ -82,6 +82,9 @@ pub struct InertialSensor {
     device: V5_DeviceT,
     rotation_offset: f64,
     heading_offset: f64,
+    calibrated_at: Option<Instant>,
+    drift_interval: Option<Duration>,
+    tap_threshold: f64,
 }
 
 // SAFETY: Required because we store a raw pointer to the device handle to avoid it getting from the
@@ -104,6 +107,16 @@ impl InertialSensor {
     /// The maximum value that can be returned by [`Self::heading`].
     pub const MAX_HEADING: f64 = 360.0;
 
+    /// Standard gravity in m/s².
+    ///
+    /// Readings from [`Self::acceleration`] are in multiples of this value, and can be multiplied
+    /// by it to obtain acceleration in m/s².
+    pub const STANDARD_GRAVITY: f64 = 9.80665;
+
+    /// The default acceleration magnitude (in multiples of [`Self::STANDARD_GRAVITY`]) above which
+    /// the sensor is considered to have been [tapped](Self::was_tapped).
+    pub const DEFAULT_TAP_THRESHOLD: f64 = 3.0;
+
     /// Create a new inertial sensor from a [`SmartPort`].
     ///
     /// # Important
@@ -132,6 +145,9 @@ impl InertialSensor {
             port,
             rotation_offset: 0.0,
             heading_offset: 0.0,
+            calibrated_at: None,
+            drift_interval: None,
+            tap_threshold: Self::DEFAULT_TAP_THRESHOLD,
         }
     }
 
@@ -175,6 +191,11 @@ impl InertialSensor {
 
     /// Returns `true` if the sensor is currently calibrating.
     ///
+    /// This reads the [`InertialStatus::CALIBRATING`] flag from the sensor and returns immediately.
+    /// Unlike awaiting [`InertialSensor::calibrate`], this can be used to check on calibration
+    /// without blocking, such as to start an autonomous routine with degraded sensing if the
+    /// sensor is taking too long to calibrate after power-on.
+    ///
     /// # Errors
     ///
     /// - An [`InertialError::Port`] error is returned if there is not an inertial sensor connected to the port.
@@ -338,6 +359,114 @@ impl InertialSensor {
         }
     }
 
+    /// Sets how long the sensor may run after calibration before its readings are considered
+    /// likely to have drifted, or `None` to disable drift detection (the default).
+    ///
+    /// Gyroscope error accumulates over time, so heading readings will slowly drift away from
+    /// reality over the course of a long match. Once this interval has elapsed since the last
+    /// calibration (or [re-zero](InertialSensor::rezero)), [`InertialSensor::is_drift_likely`]
+    /// will return `true`.
+    ///
+    /// The amount of drift depends on the individual sensor and how the robot has moved, so an
+    /// appropriate interval should be measured for your robot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut sensor = InertialSensor::new(peripherals.port_1);
+    ///     sensor.set_drift_interval(Some(Duration::from_secs(30)));
+    ///     sensor.calibrate().await.unwrap();
+    ///
+    ///     loop {
+    ///         if sensor.is_drift_likely() {
+    ///             println!("IMU heading may be inaccurate!");
+    ///         }
+    ///
+    ///         sleep(InertialSensor::UPDATE_INTERVAL).await;
+    ///     }
+    /// }
+    /// ```
+    pub const fn set_drift_interval(&mut self, interval: Option<Duration>) {
+        self.drift_interval = interval;
+    }
+
+    /// Returns the interval set by [`InertialSensor::set_drift_interval`].
+    #[must_use]
+    pub const fn drift_interval(&self) -> Option<Duration> {
+        self.drift_interval
+    }
+
+    /// Returns the time elapsed since the sensor was last calibrated or [re-zeroed](InertialSensor::rezero),
+    /// or `None` if the sensor has not been calibrated by this program.
+    #[must_use]
+    pub fn time_since_calibration(&self) -> Option<Duration> {
+        self.calibrated_at.as_ref().map(Instant::elapsed)
+    }
+
+    /// Returns `true` if the sensor's [drift interval](InertialSensor::set_drift_interval) has
+    /// elapsed since it was last calibrated or [re-zeroed](InertialSensor::rezero).
+    ///
+    /// This is an estimate based only on time, and does not measure drift directly. This always
+    /// returns `false` if no drift interval is set or the sensor has not yet been calibrated.
+    #[must_use]
+    pub fn is_drift_likely(&self) -> bool {
+        match (self.time_since_calibration(), self.drift_interval) {
+            (Some(elapsed), Some(interval)) => elapsed > interval,
+            _ => false,
+        }
+    }
+
+    /// Re-zeros the sensor's heading and rotation against a known reference heading, resetting the
+    /// drift timer used by [`InertialSensor::is_drift_likely`].
+    ///
+    /// This is a quick alternative to [calibrating](InertialSensor::calibrate) the sensor that can
+    /// be used when the robot's true heading is known, such as when squared up against a field wall.
+    ///
+    /// # Limitations
+    ///
+    /// Re-zeroing only removes the error that has accumulated so far, and does not correct the
+    /// underlying gyroscope bias that causes drift. True recalibration requires calling
+    /// [`InertialSensor::calibrate`], which takes several seconds and requires the robot to be
+    /// completely stationary. Calibrating while the robot is moving will make drift *worse*.
+    ///
+    /// # Errors
+    ///
+    /// - An [`InertialError::InvalidHeading`] error is returned if `heading` is outside of [0.0, 360.0).
+    /// - An [`InertialError::Port`] error is returned if there is not an inertial sensor connected to the port.
+    /// - An [`InertialError::BadStatus`] error is returned if the inertial sensor failed to report its status.
+    /// - An [`InertialError::StillCalibrating`] error is returned if the sensor is currently calibrating and cannot yet be used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut sensor = InertialSensor::new(peripherals.port_1);
+    ///     sensor.calibrate().await.unwrap();
+    ///
+    ///     // ...
+    ///
+    ///     // The robot is now squared against a wall facing 90 degrees.
+    ///     if sensor.is_drift_likely() {
+    ///         _ = sensor.rezero(90.0);
+    ///     }
+    /// }
+    /// ```
+    pub fn rezero(&mut self, heading: f64) -> Result<(), InertialError> {
+        self.set_heading(heading)?;
+        self.set_rotation(heading)?;
+        self.calibrated_at = Some(Instant::now());
+
+        Ok(())
+    }
+
     /// Returns the total number of degrees the Inertial Sensor has spun about the z-axis.
     ///
     /// This value is theoretically unbounded. Clockwise rotations are represented with positive degree values,
@@ -575,6 +704,9 @@ impl InertialSensor {
 
     /// Returns the sensor's raw acceleration readings in g (multiples of ~9.8 m/s/s).
     ///
+    /// These readings include the effect of gravity. To obtain acceleration in m/s², multiply
+    /// each axis by [`InertialSensor::STANDARD_GRAVITY`].
+    ///
     /// # Errors
     ///
     /// - An [`InertialError::Port`] error is returned if there is not an inertial sensor connected to the port.
@@ -609,6 +741,22 @@ impl InertialSensor {
     ///     }
     /// }
     /// ```
+    ///
+    /// Convert acceleration readings to m/s²:
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut sensor = InertialSensor::new(peripherals.port_1);
+    ///     sensor.calibrate().await.unwrap();
+    ///
+    ///     let acceleration = sensor.acceleration().unwrap();
+    ///     let z = acceleration.z * InertialSensor::STANDARD_GRAVITY;
+    ///     println!("z: {z} m/s²");
+    /// }
+    /// ```
     pub fn acceleration(&self) -> Result<Vector3<f64>, InertialError> {
         self.validate()?;
 
@@ -626,8 +774,105 @@ impl InertialSensor {
         })
     }
 
+    /// Sets the acceleration magnitude (in multiples of [`Self::STANDARD_GRAVITY`]) above which
+    /// the sensor is considered to have been [tapped](InertialSensor::was_tapped).
+    ///
+    /// The magnitude includes gravity, so a stationary sensor reads about `1.0`. Lower thresholds
+    /// detect lighter impacts, but may also be triggered by the robot's own acceleration. Defaults
+    /// to [`InertialSensor::DEFAULT_TAP_THRESHOLD`].
+    pub const fn set_tap_threshold(&mut self, threshold: f64) {
+        self.tap_threshold = threshold;
+    }
+
+    /// Returns the threshold set by [`InertialSensor::set_tap_threshold`].
+    #[must_use]
+    pub const fn tap_threshold(&self) -> f64 {
+        self.tap_threshold
+    }
+
+    /// Returns `true` if the sensor is currently experiencing a sharp impact (tap), such as from
+    /// contact with another robot.
+    ///
+    /// The sensor does not report taps itself, so this compares the magnitude of the latest
+    /// [`acceleration`](InertialSensor::acceleration) reading against the
+    /// [tap threshold](InertialSensor::set_tap_threshold). Impacts are brief, so this should be
+    /// checked at least once every [`InertialSensor::UPDATE_INTERVAL`] to avoid missing them.
+    ///
+    /// # Errors
+    ///
+    /// - An [`InertialError::Port`] error is returned if there is not an inertial sensor connected to the port.
+    /// - An [`InertialError::BadStatus`] error is returned if the inertial sensor failed to report its status.
+    /// - An [`InertialError::StillCalibrating`] error is returned if the sensor is currently calibrating and cannot yet be used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut sensor = InertialSensor::new(peripherals.port_1);
+    ///     sensor.calibrate().await.unwrap();
+    ///
+    ///     loop {
+    ///         if sensor.was_tapped().unwrap_or(false) {
+    ///             println!("Impact detected!");
+    ///         }
+    ///
+    ///         sleep(InertialSensor::UPDATE_INTERVAL).await;
+    ///     }
+    /// }
+    /// ```
+    pub fn was_tapped(&self) -> Result<bool, InertialError> {
+        let acceleration = self.acceleration()?;
+        let magnitude = (acceleration.x * acceleration.x
+            + acceleration.y * acceleration.y
+            + acceleration.z * acceleration.z)
+            .sqrt();
+
+        Ok(magnitude > self.tap_threshold)
+    }
+
+    /// Waits until the sensor is [tapped](InertialSensor::was_tapped), or until `timeout` has
+    /// elapsed.
+    ///
+    /// # Errors
+    ///
+    /// - An [`InertialError::TapTimedOut`] error is returned if no tap was detected before `timeout` elapsed.
+    /// - An [`InertialError::Port`] error is returned if there is not an inertial sensor connected to the port.
+    /// - An [`InertialError::BadStatus`] error is returned if the inertial sensor failed to report its status.
+    /// - An [`InertialError::StillCalibrating`] error is returned if the sensor is currently calibrating and cannot yet be used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    /// use vexide::devices::smart::imu::InertialError;
+    /// use core::time::Duration;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut sensor = InertialSensor::new(peripherals.port_1);
+    ///     sensor.calibrate().await.unwrap();
+    ///
+    ///     match sensor.wait_for_tap(Duration::from_secs(5)).await {
+    ///         Ok(()) => println!("Contact!"),
+    ///         Err(InertialError::TapTimedOut) => println!("No contact within 5 seconds."),
+    ///         Err(err) => println!("Error: {err}"),
+    ///     }
+    /// }
+    /// ```
+    pub fn wait_for_tap(&self, timeout: Duration) -> InertialTapFuture<'_> {
+        InertialTapFuture {
+            imu: self,
+            deadline: Instant::now() + timeout,
+        }
+    }
+
     /// Resets the current reading of the sensor's heading to zero.
     ///
+    /// This is commonly known as "taring" the sensor, making its current heading the new zero point.
+    ///
     /// This only affects the value returned by [`InertialSensor::heading`] and does not effect [`InertialSensor::rotation`]
     /// or [`InertialSensor::euler`]/[`InertialSensor::quaternion`].
     ///
@@ -660,12 +905,15 @@ impl InertialSensor {
     ///     _ = sensor.reset_heading();
     /// }
     /// ```
+    #[doc(alias = "tare")]
     pub fn reset_heading(&mut self) -> Result<(), InertialError> {
         self.set_heading(Default::default())
     }
 
     /// Resets the current reading of the sensor's rotation to zero.
     ///
+    /// This is commonly known as "taring" the sensor, making its current rotation the new zero point.
+    ///
     /// This only affects the value returned by [`InertialSensor::rotation`] and does not effect [`InertialSensor::heading`]
     /// or [`InertialSensor::euler`]/[`InertialSensor::quaternion`].
     ///
@@ -698,6 +946,7 @@ impl InertialSensor {
     ///     _ = sensor.reset_rotation();
     /// }
     /// ```
+    #[doc(alias = "tare_rotation")]
     pub fn reset_rotation(&mut self) -> Result<(), InertialError> {
         self.set_rotation(Default::default())
     }
@@ -739,8 +988,11 @@ impl InertialSensor {
     /// This only affects the value returned by [`InertialSensor::heading`] and does not effect [`InertialSensor::rotation`]
     /// or [`InertialSensor::euler`]/[`InertialSensor::quaternion`].
     ///
+    /// `heading` must be within the same range of [0.0, 360.0) degrees that [`InertialSensor::heading`] returns.
+    ///
     /// # Errors
     ///
+    /// - An [`InertialError::InvalidHeading`] error is returned if `heading` is outside of [0.0, 360.0).
     /// - An [`InertialError::Port`] error is returned if there is not an inertial sensor connected to the port.
     /// - An [`InertialError::BadStatus`] error is returned if the inertial sensor failed to report its status.
     /// - An [`InertialError::StillCalibrating`] error is returned if the sensor is currently calibrating and cannot yet be used.
@@ -759,6 +1011,10 @@ impl InertialSensor {
     /// }
     /// ```
     pub fn set_heading(&mut self, heading: f64) -> Result<(), InertialError> {
+        ensure!(
+            (0.0..Self::MAX_HEADING).contains(&heading),
+            InvalidHeadingSnafu { heading }
+        );
         self.validate()?;
 
         self.heading_offset = heading - unsafe { vexDeviceImuDegreesGet(self.device) };
@@ -804,6 +1060,56 @@ impl InertialSensor {
 
         Ok(())
     }
+
+    /// Sets the internal computation speed of the IMU in milliseconds.
+    ///
+    /// This is a stricter version of [`Self::set_data_interval`]. Rather than rounding the
+    /// requested rate, the rate is validated to be a multiple of 5 milliseconds that is at least
+    /// [`Self::MIN_DATA_INTERVAL`], since these are the only rates supported by the sensor.
+    ///
+    /// # Update Interval
+    ///
+    /// Changing the data rate does not change [`InertialSensor::UPDATE_INTERVAL`]. The brain
+    /// still only receives new data from the sensor every 10mS, so data rates faster than this
+    /// will not result in more frequent readings in user code. If a slower rate is set, readings
+    /// will only change as often as the sensor computes them, and code that polls the sensor
+    /// should sleep for the larger of the two intervals.
+    ///
+    /// # Errors
+    ///
+    /// - An [`InertialError::InvalidDataRate`] error is returned if `rate_ms` is less than 5 or is
+    ///   not a multiple of 5.
+    /// - An [`InertialError::Port`] error is returned if there is not an inertial sensor connected to the port.
+    /// - An [`InertialError::BadStatus`] error is returned if the inertial sensor failed to report its status.
+    /// - An [`InertialError::StillCalibrating`] error is returned if the sensor is currently calibrating and cannot yet be used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut sensor = InertialSensor::new(peripherals.port_1);
+    ///
+    ///     // Compute new data every 20mS.
+    ///     if let Err(err) = sensor.set_data_rate(20) {
+    ///         println!("Failed to set data rate: {err}");
+    ///     }
+    /// }
+    /// ```
+    pub fn set_data_rate(&mut self, rate_ms: u32) -> Result<(), InertialError> {
+        ensure!(
+            u128::from(rate_ms) >= Self::MIN_DATA_INTERVAL.as_millis() && rate_ms % 5 == 0,
+            InvalidDataRateSnafu { rate_ms }
+        );
+
+        self.validate()?;
+
+        unsafe { vexDeviceImuDataRateSet(self.device, rate_ms) }
+
+        Ok(())
+    }
 }
 
 impl SmartDevice for InertialSensor {
@@ -1037,6 +1343,7 @@ impl core::future::Future for InertialCalibrateFuture<'_> {
                     && phase == CalibrationPhase::End
                 {
                     // The [`InertialStatus::CALIBRATING`] has been cleared, indicating that calibration is complete.
+                    this.imu.calibrated_at = Some(Instant::now());
                     return Poll::Ready(Ok(()));
                 }
 
@@ -1047,6 +1354,34 @@ impl core::future::Future for InertialCalibrateFuture<'_> {
     }
 }
 
+/// Future that waits for an IMU to be tapped
+/// created with [`InertialSensor::wait_for_tap`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct InertialTapFuture<'a> {
+    imu: &'a InertialSensor,
+    deadline: Instant,
+}
+
+impl core::future::Future for InertialTapFuture<'_> {
+    type Output = Result<(), InertialError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.imu.was_tapped() {
+            Ok(true) => return Poll::Ready(Ok(())),
+            Ok(false) => {}
+            Err(err) => return Poll::Ready(Err(err)),
+        }
+
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(TapTimedOutSnafu.fail());
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
 /// Errors that can occur when interacting with an Inertial Sensor.
 #[derive(Debug, Snafu)]
 pub enum InertialError {
@@ -1054,8 +1389,24 @@ pub enum InertialError {
     CalibrationTimedOut,
     /// The sensor is still calibrating.
     StillCalibrating,
+    /// No tap was detected before the timeout passed to [`InertialSensor::wait_for_tap`] elapsed.
+    TapTimedOut,
     /// The sensor failed to report its status flags (returned 0xFF).
     BadStatus,
+    /// The requested data rate is not supported by the sensor.
+    ///
+    /// Data rates must be a multiple of 5 milliseconds, and no faster than 5 milliseconds.
+    #[snafu(display("Unsupported data rate of {rate_ms}mS (must be a multiple of 5mS)."))]
+    InvalidDataRate {
+        /// The requested data rate in milliseconds.
+        rate_ms: u32,
+    },
+    /// The requested heading is outside of the range [0.0, 360.0) degrees.
+    #[snafu(display("Heading of {heading} degrees is outside of [0.0, 360.0)."))]
+    InvalidHeading {
+        /// The requested heading in degrees.
+        heading: f64,
+    },
     /// Generic port related error.
     #[snafu(transparent)]
     Port {
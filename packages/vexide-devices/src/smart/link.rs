@@ -55,6 +55,14 @@ impl RadioLink {
     /// specific radio will be disabled.
     /// Other radios connected to the Brain can take over this functionality.
     ///
+    /// # Link Pairing
+    ///
+    /// Two radios will only link with each other if they were opened with the same `id`, so
+    /// giving each robot-to-robot pair a unique `id` allows multiple pairs to operate on the
+    /// same field. The radio channel itself is chosen by VEXos and is not exposed by the SDK,
+    /// so it cannot be selected or queried from user code. Links share spectrum with VEXnet
+    /// controller connections, so many active links nearby may reduce throughput for all of them.
+    ///
     /// # Errors
     ///
     /// - A [`NulError`] error is returned if a NUL (0x00) character was found anywhere in the specified `id`.
@@ -31,7 +31,10 @@
 //!
 //! [`peripherals`]: crate::peripherals
 
-use core::fmt;
+use core::{
+    fmt,
+    sync::atomic::{AtomicU32, AtomicU8, Ordering},
+};
 
 use vex_sdk::{
     vexDeviceGetByIndex, vexDeviceGetStatus, vexDeviceGetTimestamp, V5_DeviceT, V5_DeviceType,
@@ -112,12 +115,11 @@ pub trait SmartDevice {
     /// }
     /// ```
     fn is_connected(&self) -> bool {
-        let mut device_types: [V5_DeviceType; V5_MAX_DEVICE_PORTS] = unsafe { core::mem::zeroed() };
-        unsafe {
-            vexDeviceGetStatus(device_types.as_mut_ptr());
-        }
+        let index = (self.port_number() - 1) as usize;
+        let connected_type = connected_device_types()[index];
+        track_connection(index, connected_type.is_some());
 
-        SmartDeviceType::from(device_types[(self.port_number() - 1) as usize]) == self.device_type()
+        connected_type == Some(self.device_type())
     }
 
     /// Returns the timestamp recorded by this device's internal clock.
@@ -140,22 +142,99 @@ pub trait SmartDevice {
     fn validate_port(&self) -> Result<(), PortError> {
         validate_port(self.port_number(), self.device_type())
     }
+
+    /// Returns the number of times a device has been reconnected to this device's [`SmartPort`]
+    /// after being disconnected.
+    ///
+    /// This is useful for diagnosing intermittent connections, such as from a damaged cable or
+    /// loose port.
+    ///
+    /// # Detection
+    ///
+    /// Disconnects are detected by polling the status of the port, which happens whenever this
+    /// function or [`SmartDevice::is_connected`] is called. A disconnect that is shorter than the
+    /// interval between these checks will not be counted, so this function should be called
+    /// periodically for an accurate count.
+    ///
+    /// Connection state is tracked per Smart Port rather than per device instance. Counts are
+    /// stored in memory and reset to zero when the program restarts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///
+    ///     loop {
+    ///         let reconnects = motor.reconnect_count();
+    ///         if reconnects > 0 {
+    ///             println!("Motor has reconnected {reconnects} times. Check the cable!");
+    ///         }
+    ///
+    ///         sleep(Motor::UPDATE_INTERVAL).await;
+    ///     }
+    /// }
+    /// ```
+    fn reconnect_count(&self) -> u32 {
+        let index = (self.port_number() - 1) as usize;
+        track_connection(index, connected_device_types()[index].is_some());
+
+        RECONNECT_COUNTS[index].load(Ordering::Acquire)
+    }
 }
 
-/// Verify that the device type is currently plugged into this port.
+/// The connection state of a port has not been observed yet.
+const PORT_STATE_UNKNOWN: u8 = 0;
+/// A device was connected to the port when it was last observed.
+const PORT_STATE_CONNECTED: u8 = 1;
+/// No device was connected to the port when it was last observed.
+const PORT_STATE_DISCONNECTED: u8 = 2;
+
+/// The last observed connection state of each Smart Port.
+static PORT_STATES: [AtomicU8; V5_MAX_DEVICE_PORTS] =
+    [const { AtomicU8::new(PORT_STATE_UNKNOWN) }; V5_MAX_DEVICE_PORTS];
+
+/// The number of times a device has reconnected to each Smart Port.
+static RECONNECT_COUNTS: [AtomicU32; V5_MAX_DEVICE_PORTS] =
+    [const { AtomicU32::new(0) }; V5_MAX_DEVICE_PORTS];
+
+/// Records the connection state of a port, counting a reconnect if it was previously disconnected.
+fn track_connection(index: usize, connected: bool) {
+    let state = if connected {
+        PORT_STATE_CONNECTED
+    } else {
+        PORT_STATE_DISCONNECTED
+    };
+
+    if PORT_STATES[index].swap(state, Ordering::AcqRel) == PORT_STATE_DISCONNECTED && connected {
+        RECONNECT_COUNTS[index].fetch_add(1, Ordering::AcqRel);
+    }
+}
+
+/// Returns the type of device plugged into each Smart Port, indexed by port number minus one.
 ///
-/// This function provides the internal implementations of [`SmartDevice::validate_port`], [`SmartPort::validate_type`],
-/// and [`AdiPort::validate_expander`].
-pub(crate) fn validate_port(number: u8, device_type: SmartDeviceType) -> Result<(), PortError> {
+/// Ports with no device plugged in are `None`.
+pub(crate) fn connected_device_types() -> [Option<SmartDeviceType>; V5_MAX_DEVICE_PORTS] {
     let mut device_types: [V5_DeviceType; V5_MAX_DEVICE_PORTS] = unsafe { core::mem::zeroed() };
     unsafe {
         vexDeviceGetStatus(device_types.as_mut_ptr());
     }
 
-    let connected_type: Option<SmartDeviceType> = match device_types[(number - 1) as usize] {
+    core::array::from_fn(|index| match device_types[index] {
         V5_DeviceType::kDeviceTypeNoSensor => None,
         raw_type => Some(raw_type.into()),
-    };
+    })
+}
+
+/// Verify that the device type is currently plugged into this port.
+///
+/// This function provides the internal implementations of [`SmartDevice::validate_port`], [`SmartPort::validate_type`],
+/// and [`AdiPort::validate_expander`].
+pub(crate) fn validate_port(number: u8, device_type: SmartDeviceType) -> Result<(), PortError> {
+    let connected_type = connected_device_types()[(number - 1) as usize];
 
     if let Some(connected_type) = connected_type {
         // The connected device must match the requested type.
@@ -241,15 +320,7 @@ impl SmartPort {
     /// ```
     #[must_use]
     pub fn device_type(&self) -> Option<SmartDeviceType> {
-        let mut device_types: [V5_DeviceType; V5_MAX_DEVICE_PORTS] = unsafe { core::mem::zeroed() };
-        unsafe {
-            vexDeviceGetStatus(device_types.as_mut_ptr());
-        }
-
-        match device_types[self.index() as usize] {
-            V5_DeviceType::kDeviceTypeNoSensor => None,
-            raw_type => Some(raw_type.into()),
-        }
+        connected_device_types()[self.index() as usize]
     }
 
     /// Verify that a device type is currently plugged into this port, returning an appropriate
@@ -50,24 +50,32 @@
 //! The [`Motor`] API can make use of these builtin control features through the [`MotorControl`] type,
 //! which describes an action that the motor should perform.
 
-use core::time::Duration;
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    str::FromStr,
+    task::{Context, Poll},
+    time::Duration,
+};
 
 use bitflags::bitflags;
 use snafu::{ensure, Snafu};
 use vex_sdk::{
-    vexDeviceMotorAbsoluteTargetSet, vexDeviceMotorActualVelocityGet, vexDeviceMotorBrakeModeSet,
-    vexDeviceMotorCurrentGet, vexDeviceMotorCurrentLimitGet, vexDeviceMotorCurrentLimitSet,
-    vexDeviceMotorEfficiencyGet, vexDeviceMotorEncoderUnitsSet, vexDeviceMotorFaultsGet,
-    vexDeviceMotorFlagsGet, vexDeviceMotorGearingGet, vexDeviceMotorGearingSet,
-    vexDeviceMotorPositionGet, vexDeviceMotorPositionRawGet, vexDeviceMotorPositionReset,
-    vexDeviceMotorPositionSet, vexDeviceMotorPowerGet, vexDeviceMotorReverseFlagGet,
-    vexDeviceMotorReverseFlagSet, vexDeviceMotorTemperatureGet, vexDeviceMotorTorqueGet,
-    vexDeviceMotorVelocitySet, vexDeviceMotorVelocityUpdate, vexDeviceMotorVoltageGet,
-    vexDeviceMotorVoltageLimitGet, vexDeviceMotorVoltageLimitSet, vexDeviceMotorVoltageSet,
-    V5MotorBrakeMode, V5MotorGearset, V5_DeviceT,
+    vexDeviceMotorAbsoluteTargetSet, vexDeviceMotorActualVelocityGet, vexDeviceMotorBrakeModeGet,
+    vexDeviceMotorBrakeModeSet, vexDeviceMotorCurrentGet, vexDeviceMotorCurrentLimitGet,
+    vexDeviceMotorCurrentLimitSet, vexDeviceMotorEfficiencyGet, vexDeviceMotorEncoderUnitsSet,
+    vexDeviceMotorFaultsGet, vexDeviceMotorFlagsGet, vexDeviceMotorGearingGet,
+    vexDeviceMotorGearingSet, vexDeviceMotorPositionGet, vexDeviceMotorPositionRawGet,
+    vexDeviceMotorPositionReset, vexDeviceMotorPositionSet, vexDeviceMotorPowerGet,
+    vexDeviceMotorReverseFlagGet, vexDeviceMotorReverseFlagSet, vexDeviceMotorTemperatureGet,
+    vexDeviceMotorTorqueGet, vexDeviceMotorVelocitySet, vexDeviceMotorVelocityUpdate,
+    vexDeviceMotorVoltageGet, vexDeviceMotorVoltageLimitGet, vexDeviceMotorVoltageLimitSet,
+    vexDeviceMotorVoltageSet, V5MotorBrakeMode, V5MotorGearset, V5_DeviceT,
 };
 #[cfg(feature = "dangerous_motor_tuning")]
 use vex_sdk::{vexDeviceMotorPositionPidSet, vexDeviceMotorVelocityPidSet, V5_DeviceMotorPid};
+use vexide_core::{float::Float, time::Instant};
 
 use super::{SmartDevice, SmartDeviceTimestamp, SmartDeviceType, SmartPort};
 use crate::{position::Position, PortError};
@@ -80,6 +88,72 @@ pub struct Motor {
     device: V5_DeviceT,
 
     motor_type: MotorType,
+    velocity_sample: Option<VelocitySample>,
+    velocity_history: VelocityHistory,
+    last_slew_update: Instant,
+    slew_rate: Option<f64>,
+    energy: f64,
+    power_sample: Option<PowerSample>,
+
+    #[cfg(feature = "dangerous_motor_tuning")]
+    velocity_tuning_constants: Option<MotorTuningConstants>,
+    #[cfg(feature = "dangerous_motor_tuning")]
+    position_tuning_constants: Option<MotorTuningConstants>,
+}
+
+/// The last position sample taken by [`Motor::computed_velocity`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct VelocitySample {
+    position: Position,
+    timestamp: SmartDeviceTimestamp,
+    velocity: Option<f64>,
+}
+
+/// The last power sample taken by [`Motor::cumulative_energy`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PowerSample {
+    power: f64,
+    timestamp: Instant,
+}
+
+/// Recent velocity readings used by [`Motor::velocity_filtered`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct VelocityHistory {
+    samples: [f64; Motor::MAX_VELOCITY_WINDOW],
+    len: usize,
+    next: usize,
+}
+
+impl VelocityHistory {
+    const fn new() -> Self {
+        Self {
+            samples: [0.0; Motor::MAX_VELOCITY_WINDOW],
+            len: 0,
+            next: 0,
+        }
+    }
+
+    const fn push(&mut self, sample: f64) {
+        self.samples[self.next] = sample;
+        self.next = (self.next + 1) % Motor::MAX_VELOCITY_WINDOW;
+        if self.len < Motor::MAX_VELOCITY_WINDOW {
+            self.len += 1;
+        }
+    }
+
+    /// Returns the mean of the `window` most recent samples.
+    fn mean(&self, window: usize) -> f64 {
+        let count = window.min(self.len);
+        let sum: f64 = (1..=count)
+            .map(|age| {
+                let index =
+                    (self.next + Motor::MAX_VELOCITY_WINDOW - age) % Motor::MAX_VELOCITY_WINDOW;
+                self.samples[index]
+            })
+            .sum();
+
+        sum / f64::from(count as u32)
+    }
 }
 
 // SAFETY: Required because we store a raw pointer to the device handle to avoid it getting from the
@@ -198,6 +272,9 @@ impl Motor {
     /// The interval at which the Brain will send new packets to a [`Motor`].
     pub const WRITE_INTERVAL: Duration = Duration::from_millis(5);
 
+    /// The largest window size that can be passed to [`Motor::velocity_filtered`].
+    pub const MAX_VELOCITY_WINDOW: usize = 32;
+
     /// Create a new V5 or EXP motor.
     #[must_use]
     fn new_with_type(
@@ -211,6 +288,9 @@ impl Motor {
         // NOTE: SDK properly stores device state when unplugged, meaning that we can safely
         // set these without consequence even if the device is not available. This is an edge
         // case for the SDK though, and seems to just be a thing for motors and rotation sensors.
+        //
+        // Positions are always read in raw counts and converted by `Position`, so the
+        // encoder units must never be changed from this.
         unsafe {
             vexDeviceMotorEncoderUnitsSet(
                 device,
@@ -226,6 +306,16 @@ impl Motor {
             target: MotorControl::Voltage(0.0),
             device,
             motor_type,
+            velocity_sample: None,
+            velocity_history: VelocityHistory::new(),
+            last_slew_update: Instant::now(),
+            slew_rate: None,
+            energy: 0.0,
+            power_sample: None,
+            #[cfg(feature = "dangerous_motor_tuning")]
+            velocity_tuning_constants: None,
+            #[cfg(feature = "dangerous_motor_tuning")]
+            position_tuning_constants: None,
         }
     }
 
@@ -292,6 +382,7 @@ impl Motor {
     pub fn set_target(&mut self, target: MotorControl) -> Result<(), MotorError> {
         let gearset = self.gearset()?;
         self.target = target;
+        self.last_slew_update = Instant::now();
 
         match target {
             MotorControl::Brake(mode) => unsafe {
@@ -352,6 +443,36 @@ impl Motor {
         self.set_target(MotorControl::Brake(mode))
     }
 
+    /// Returns the brake mode currently configured on the motor.
+    ///
+    /// The brake mode is only set by [`MotorControl::Brake`] targets. All other targets (voltage,
+    /// velocity, and position) switch the motor to [`BrakeMode::Coast`], so this returns
+    /// [`BrakeMode::Coast`] unless the motor is currently braking.
+    ///
+    /// # Errors
+    ///
+    /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///     let _ = motor.brake(BrakeMode::Hold);
+    ///
+    ///     if let Ok(mode) = motor.brake_mode() {
+    ///         println!("Brake mode: {mode}");
+    ///     }
+    /// }
+    /// ```
+    pub fn brake_mode(&self) -> Result<BrakeMode, MotorError> {
+        self.validate_port()?;
+        Ok(unsafe { vexDeviceMotorBrakeModeGet(self.device) }.into())
+    }
+
     /// Spins the motor at a target velocity.
     ///
     /// This velocity corresponds to different actual speeds in RPM depending on the gearset used for the motor.
@@ -385,6 +506,9 @@ impl Motor {
     /// This voltage value spans from -12 (fully spinning reverse) to +12 (fully spinning forwards) volts, and
     /// controls the raw output of the motor.
     ///
+    /// If a [slew rate](Motor::set_slew_rate) is configured, this behaves like
+    /// [`Motor::set_voltage_slewed`] with that rate.
+    ///
     /// # Errors
     ///
     /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
@@ -422,7 +546,109 @@ impl Motor {
     /// }
     /// ```
     pub fn set_voltage(&mut self, volts: f64) -> Result<(), MotorError> {
-        self.set_target(MotorControl::Voltage(volts))
+        match self.slew_rate {
+            Some(slew_rate) => self.set_voltage_slewed(volts, slew_rate),
+            None => self.set_target(MotorControl::Voltage(volts)),
+        }
+    }
+
+    /// Moves the motor's output voltage towards a target, changing by at most `slew_rate` volts
+    /// per second.
+    ///
+    /// This limits how quickly the motor's voltage can change, which reduces wheel slip and
+    /// mechanical stress on drivetrains from sudden changes in direction. It is intended to be
+    /// called repeatedly (such as once per loop iteration), with each call stepping the voltage
+    /// further towards `volts` based on the time elapsed since the previous call.
+    ///
+    /// The voltage is stepped from the motor's current [voltage target](MotorControl::Voltage),
+    /// or from zero if the motor is not currently under voltage control. The first step is based on
+    /// the time elapsed since the motor was created, since its [slew rate](Motor::set_slew_rate)
+    /// was set, or since it was last given a different target, whichever happened most recently.
+    ///
+    /// # Errors
+    ///
+    /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// Drive the motor from a joystick, changing by at most 24 volts per second:
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///     let controller = peripherals.primary_controller;
+    ///
+    ///     loop {
+    ///         let controller_state = controller.state().unwrap_or_default();
+    ///         let voltage = controller_state.left_stick.y() * motor.max_voltage();
+    ///         _ = motor.set_voltage_slewed(voltage, 24.0);
+    ///
+    ///         sleep(Motor::UPDATE_INTERVAL).await;
+    ///     }
+    /// }
+    /// ```
+    pub fn set_voltage_slewed(&mut self, volts: f64, slew_rate: f64) -> Result<(), MotorError> {
+        let now = Instant::now();
+
+        let current = match self.target {
+            MotorControl::Voltage(current) => current,
+            _ => 0.0,
+        };
+        let max_step = (slew_rate * (now - self.last_slew_update).as_secs_f64()).max(0.0);
+
+        self.set_target(MotorControl::Voltage(
+            current + (volts - current).clamp(-max_step, max_step),
+        ))?;
+        self.last_slew_update = now;
+
+        Ok(())
+    }
+
+    /// Sets a slew rate (in volts per second) that limits how quickly [`Motor::set_voltage`] can
+    /// change the motor's voltage, or disables slew limiting if `None`.
+    ///
+    /// A slew rate of 1 volt per second is equivalent to 1 millivolt per millisecond.
+    ///
+    /// VEXos does not support slew limiting in the motor itself, so this is done by the brain in
+    /// the same way as [`Motor::set_voltage_slewed`]. As a result, [`Motor::set_voltage`] must be
+    /// called repeatedly (such as once per loop iteration) for the voltage to reach its target.
+    /// The ramp is timed from when the slew rate is set, so the first call afterwards already
+    /// moves the voltage by up to `slew_rate` volts for each second that has passed since then.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///     let controller = peripherals.primary_controller;
+    ///
+    ///     // Ramp from stopped to full speed over half a second.
+    ///     motor.set_slew_rate(Some(24.0));
+    ///
+    ///     loop {
+    ///         let controller_state = controller.state().unwrap_or_default();
+    ///         _ = motor.set_voltage(controller_state.left_stick.y() * motor.max_voltage());
+    ///
+    ///         sleep(Motor::UPDATE_INTERVAL).await;
+    ///     }
+    /// }
+    /// ```
+    pub fn set_slew_rate(&mut self, slew_rate: Option<f64>) {
+        self.slew_rate = slew_rate;
+        self.last_slew_update = Instant::now();
+    }
+
+    /// Returns the slew rate (in volts per second) set by [`Motor::set_slew_rate`], or `None` if
+    /// slew limiting is disabled.
+    #[must_use]
+    pub const fn slew_rate(&self) -> Option<f64> {
+        self.slew_rate
     }
 
     /// Sets an absolute position target for the motor to attempt to reach.
@@ -451,6 +677,43 @@ impl Motor {
         self.set_target(MotorControl::Position(position, velocity))
     }
 
+    /// Commands the motor to hold its current position.
+    ///
+    /// This reads the motor's current [position](Motor::position) and sets it as the motor's
+    /// [position target](Motor::set_position_target), so the motor's position controller
+    /// actively drives it back to this point if it is pushed away by an external load.
+    ///
+    /// This differs from braking with [`BrakeMode::Hold`] in that the held position is captured
+    /// immediately, rather than wherever the motor ends up once it has come to a stop.
+    ///
+    /// # Errors
+    ///
+    /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// Raise an arm, then keep it in place against gravity:
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut arm = Motor::new(peripherals.port_1, Gearset::Red, Direction::Forward);
+    ///
+    ///     _ = arm.set_voltage(6.0);
+    ///     sleep(Duration::from_millis(500)).await;
+    ///     _ = arm.hold_position();
+    /// }
+    /// ```
+    pub fn hold_position(&mut self) -> Result<(), MotorError> {
+        let position = self.position()?;
+        let velocity = self.gearset()?.max_rpm() as i32;
+
+        self.set_position_target(position, velocity)
+    }
+
     /// Changes the output velocity for a profiled movement (motor_move_absolute or motor_move_relative).
     ///
     /// This will have no effect if the motor is not following a profiled movement.
@@ -489,6 +752,15 @@ impl Motor {
     /// Returns the current [`MotorControl`] target that the motor is attempting to use.
     /// This value is set with [`Motor::set_target`].
     ///
+    /// This is useful for debugging motion control code, since it reports whether the motor is
+    /// currently being commanded to brake or to reach a voltage, velocity, or position. Every
+    /// method that commands the motor (such as [`Motor::set_voltage`] or [`Motor::brake`]) goes
+    /// through [`Motor::set_target`], so this always reflects the most recent command.
+    ///
+    /// VEXos does not report which kind of command a motor is executing, so this is tracked by
+    /// vexide rather than read back from the motor. It is not affected by commands sent to the
+    /// same motor by other [`Motor`] instances.
+    ///
     /// # Examples
     ///
     /// ```
@@ -501,6 +773,7 @@ impl Motor {
     ///     let target = motor.target();
     ///     assert_eq!(target, MotorControl::Brake(BrakeMode::Hold));
     /// }
+    /// ```
     #[must_use]
     pub const fn target(&self) -> MotorControl {
         self.target
@@ -555,13 +828,13 @@ impl Motor {
     ///         println!("Failed to get gearset. Is this an EXP motor?");
     ///         return;
     ///     };
-    ///     match motor.gearset() {
+    ///     match gearset {
     ///         Gearset::Green => println!("Motor is using the green gearset"),
     ///         Gearset::Red => println!("Motor is using the red gearset"),
     ///         Gearset::Blue => println!("Motor is using the blue gearset"),
-    ///    }
+    ///     }
     /// }
-    ///
+    /// ```
     pub fn gearset(&self) -> Result<Gearset, MotorError> {
         if self.motor_type.is_exp() {
             return Ok(Gearset::Green);
@@ -707,8 +980,129 @@ impl Motor {
         Ok(unsafe { vexDeviceMotorActualVelocityGet(self.device) })
     }
 
+    /// Returns the mean of the motor's most recent velocity readings in rotations per minute (RPM).
+    ///
+    /// Each call reads [`Motor::velocity`] and stores it in an internal buffer, then returns the
+    /// average of the last `window` readings (including the new one). This smooths out noise caused
+    /// by encoder quantization at the cost of some lag. Until `window` readings have been collected,
+    /// the average of all readings taken so far is returned.
+    ///
+    /// The buffer holds at most [`Motor::MAX_VELOCITY_WINDOW`] readings, so `window` is clamped
+    /// to the range [1, 32]. Since a reading is stored on every call, this should be called at a
+    /// regular interval (ideally [`Motor::UPDATE_INTERVAL`]) for the window to represent a
+    /// consistent period of time.
+    ///
+    /// # Errors
+    ///
+    /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///     motor.set_voltage(motor.max_voltage()).unwrap();
+    ///
+    ///     loop {
+    ///         // Average over the last 100ms of readings.
+    ///         println!("Velocity: {:.2} RPM", motor.velocity_filtered(10).unwrap());
+    ///         sleep(Motor::UPDATE_INTERVAL).await;
+    ///     }
+    /// }
+    /// ```
+    pub fn velocity_filtered(&mut self, window: usize) -> Result<f64, MotorError> {
+        let velocity = self.velocity()?;
+        self.velocity_history.push(velocity);
+
+        Ok(self
+            .velocity_history
+            .mean(window.clamp(1, Self::MAX_VELOCITY_WINDOW)))
+    }
+
+    /// Computes the angular velocity of the motor in rotations per minute (RPM) by differentiating
+    /// its position over time.
+    ///
+    /// This is an alternative to the firmware's estimate returned by [`Motor::velocity`], and is
+    /// mainly useful for diagnostics. Comparing the two values can help identify a faulty encoder
+    /// or a motor that is reporting stale data.
+    ///
+    /// # Sampling
+    ///
+    /// Each call samples [`Motor::position`] along with the motor's internal [`Motor::timestamp`],
+    /// and computes the change in position since the previous sample. Because of this:
+    ///
+    /// - The first call (and the first call after the motor's clock resets due to a disconnect) only
+    ///   records a sample and returns `None`.
+    /// - New data is only produced by the motor every [`Motor::UPDATE_INTERVAL`]. Calling this more
+    ///   often than that will return the previously computed value until a new packet arrives.
+    /// - The result is averaged over the time between calls, so this should be called at a regular
+    ///   interval (ideally [`Motor::UPDATE_INTERVAL`]) for the value to be meaningful.
+    ///
+    /// # Accuracy
+    ///
+    /// At a steady speed, this value should closely agree with [`Motor::velocity`]. During rapid
+    /// acceleration the two may differ by a few RPM, since the firmware applies its own filtering
+    /// to the reported velocity. At low speeds, the 1ms timestamp resolution and encoder tick
+    /// quantization make this value noticeably noisier than the firmware estimate. A persistent
+    /// disagreement while the motor is spinning at a constant speed likely indicates a problem.
+    ///
+    /// # Errors
+    ///
+    /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// Compare the computed velocity of a motor with the firmware's estimate:
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///     motor.set_voltage(motor.max_voltage()).unwrap();
+    ///
+    ///     loop {
+    ///         if let Some(computed) = motor.computed_velocity().unwrap() {
+    ///             let reported = motor.velocity().unwrap();
+    ///             println!("Reported: {reported:.2} RPM, Computed: {computed:.2} RPM");
+    ///         }
+    ///         sleep(Motor::UPDATE_INTERVAL).await;
+    ///     }
+    /// }
+    /// ```
+    pub fn computed_velocity(&mut self) -> Result<Option<f64>, MotorError> {
+        let position = self.position()?;
+        let timestamp = self.timestamp()?;
+
+        let velocity = match self.velocity_sample {
+            // No new data has been received from the motor since the last sample.
+            Some(sample) if sample.timestamp == timestamp => return Ok(sample.velocity),
+            // The motor's clock has not been reset since the last sample.
+            Some(sample) if sample.timestamp < timestamp => {
+                let elapsed_minutes = f64::from(timestamp.0 - sample.timestamp.0) / 60_000.0;
+                Some((position - sample.position).as_revolutions() / elapsed_minutes)
+            }
+            _ => None,
+        };
+
+        self.velocity_sample = Some(VelocitySample {
+            position,
+            timestamp,
+            velocity,
+        });
+
+        Ok(velocity)
+    }
+
     /// Returns the power drawn by the motor in Watts.
     ///
+    /// This is measured by the motor itself, and is equivalent to the product of the motor's
+    /// [current](Motor::current) in amps and the [voltage](Motor::voltage) applied to it in volts.
+    ///
     /// # Errors
     ///
     /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
@@ -733,6 +1127,70 @@ impl Motor {
         Ok(unsafe { vexDeviceMotorPowerGet(self.device) })
     }
 
+    /// Returns the total energy used by the motor in Joules since the first call to this
+    /// function or the last call to [`Motor::reset_cumulative_energy`].
+    ///
+    /// Energy is computed by integrating [`Motor::power`] over time. Each call samples the
+    /// motor's power and adds the energy used since the previous sample, so the first call only
+    /// records a sample and returns `0.0`. This should be called at a regular interval (ideally
+    /// [`Motor::UPDATE_INTERVAL`]) for the result to be accurate, since changes in power between
+    /// calls are not observed.
+    ///
+    /// # Errors
+    ///
+    /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///     motor.set_voltage(motor.max_voltage()).unwrap();
+    ///
+    ///     loop {
+    ///         println!("Energy used: {:.2}J", motor.cumulative_energy().unwrap());
+    ///         sleep(Motor::UPDATE_INTERVAL).await;
+    ///     }
+    /// }
+    /// ```
+    pub fn cumulative_energy(&mut self) -> Result<f64, MotorError> {
+        let power = self.power()?;
+        let timestamp = Instant::now();
+
+        if let Some(sample) = self.power_sample {
+            // Trapezoidal integration between this sample and the last.
+            let elapsed = (timestamp - sample.timestamp).as_secs_f64();
+            self.energy += (sample.power + power) / 2.0 * elapsed;
+        }
+
+        self.power_sample = Some(PowerSample { power, timestamp });
+
+        Ok(self.energy)
+    }
+
+    /// Resets the total energy returned by [`Motor::cumulative_energy`] to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///
+    ///     // Only measure energy used during autonomous.
+    ///     motor.reset_cumulative_energy();
+    /// }
+    /// ```
+    pub const fn reset_cumulative_energy(&mut self) {
+        self.energy = 0.0;
+        self.power_sample = None;
+    }
+
     /// Returns the torque output of the motor in Nm.
     ///
     /// # Errors
@@ -788,6 +1246,13 @@ impl Motor {
 
     /// Returns the current position of the motor.
     ///
+    /// The returned [`Position`] is measured at the output shaft of the motor's gearset, and can be
+    /// converted to any unit using methods such as [`Position::as_revolutions`] or
+    /// [`Position::as_degrees`]. For the unconverted encoder tick count, see [`Motor::raw_position`].
+    ///
+    /// Because [`Position`] is not tied to a unit, motors do not need to be configured with the units
+    /// that positions are reported in.
+    ///
     /// # Errors
     ///
     /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
@@ -808,6 +1273,38 @@ impl Motor {
     ///     }
     /// }
     /// ```
+    ///
+    /// Print the number of revolutions a motor has made:
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///
+    ///     if let Ok(position) = motor.position() {
+    ///         println!("Revolutions: {}", position.as_revolutions());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Print the position of a motor in encoder ticks at the output shaft:
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///
+    ///     if let Ok(position) = motor.position() {
+    ///         let ticks = position.as_ticks(Gearset::Green.ticks_per_revolution());
+    ///         println!("Ticks: {ticks}");
+    ///     }
+    /// }
+    /// ```
+    #[doc(alias = "encoder_units")]
     pub fn position(&self) -> Result<Position, MotorError> {
         let gearset = self.gearset()?;
         Ok(Position::from_ticks(
@@ -816,6 +1313,118 @@ impl Motor {
         ))
     }
 
+    /// Waits until the motor's [position](Motor::position) is within `tolerance` of `target`.
+    ///
+    /// This does not command the motor to move. It is intended for cases where the motor is
+    /// already being driven towards `target`, such as with [`Motor::set_position_target`] or by
+    /// an external motion profile, and the caller only needs to know when it has arrived.
+    ///
+    /// The motor's position is only read once every [`Motor::UPDATE_INTERVAL`], since new data
+    /// is not available from the motor any more often than that.
+    ///
+    /// # Errors
+    ///
+    /// The returned future will resolve to:
+    ///
+    /// - A [`MotorError::Port`] error if a motor device is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///
+    ///     let target = Position::from_degrees(90.0);
+    ///     _ = motor.set_position_target(target, 200);
+    ///
+    ///     // Wait until the motor is within 2 degrees of the target.
+    ///     if motor
+    ///         .wait_until_position(target, Position::from_degrees(2.0))
+    ///         .await
+    ///         .is_ok()
+    ///     {
+    ///         println!("Motor reached its target!");
+    ///     }
+    /// }
+    /// ```
+    pub fn wait_until_position(
+        &self,
+        target: Position,
+        tolerance: Position,
+    ) -> MotorPositionFuture<'_> {
+        MotorPositionFuture {
+            motor: self,
+            target,
+            tolerance,
+            next_update: Instant::now(),
+        }
+    }
+
+    /// Waits until the motor can coast to a stop at `target`, then stops applying power to it.
+    ///
+    /// The motor should already be moving towards `target` when this is called (for example,
+    /// using [`Motor::set_voltage`]). Each [`Motor::UPDATE_INTERVAL`], the distance that the motor
+    /// would travel while coasting to a stop is estimated from its current [velocity] and the
+    /// given `deceleration`. Once that distance covers the remaining distance to `target`, the
+    /// motor is switched to [`BrakeMode::Coast`] and the future completes. If the motor is moving
+    /// away from `target` or has already passed it, the motor is switched to coast immediately.
+    ///
+    /// # Measuring Deceleration
+    ///
+    /// `deceleration` is the rate at which the motor slows down while coasting, in RPM per second.
+    /// This depends on the load on the motor and the friction in its mechanism, so it should be
+    /// measured on the robot. This can be done by spinning the motor up to speed, switching it to
+    /// coast, and dividing its initial velocity by the time it takes to stop.
+    ///
+    /// [velocity]: Motor::velocity
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `deceleration` is not greater than zero.
+    ///
+    /// # Errors
+    ///
+    /// The returned future will resolve to:
+    ///
+    /// - A [`MotorError::Port`] error if a motor device is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///     _ = motor.set_voltage(6.0);
+    ///
+    ///     // This mechanism was measured to slow down at about 400 RPM/s while coasting.
+    ///     _ = motor
+    ///         .coast_to_position(Position::from_revolutions(5.0), 400.0)
+    ///         .await;
+    /// }
+    /// ```
+    pub fn coast_to_position(
+        &mut self,
+        target: Position,
+        deceleration: f64,
+    ) -> MotorCoastFuture<'_> {
+        assert!(
+            deceleration > 0.0,
+            "Coasting deceleration must be greater than zero."
+        );
+
+        MotorCoastFuture {
+            motor: self,
+            target,
+            deceleration,
+            next_update: Instant::now(),
+        }
+    }
+
     /// Returns the most recently recorded raw encoder tick data from the motor's IME
     /// along with a timestamp of the internal clock of the motor indicating when the
     /// data was recorded.
@@ -881,6 +1490,20 @@ impl Motor {
     /// drawing no electrical power, and an efficiency of 0.0 means that the motor
     /// is drawing power but not moving.
     ///
+    /// A sustained drop in efficiency under a consistent load can be a sign of mechanical
+    /// binding, friction, or wear in the drivetrain attached to the motor.
+    ///
+    /// # Accuracy
+    ///
+    /// Efficiency is not measured directly. It is estimated by the motor's firmware from its
+    /// voltage, current, and velocity, so any noise in those readings also affects this value.
+    /// In particular, readings are unreliable at very low speeds or currents, where small errors
+    /// in the underlying measurements have a large effect on the ratio. For this reason,
+    /// efficiency is best compared between similar operating conditions rather than treated as
+    /// an absolute measurement.
+    ///
+    /// Multiply the result by 100.0 to obtain a percentage.
+    ///
     /// # Errors
     ///
     /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
@@ -893,10 +1516,10 @@ impl Motor {
     ///
     /// #[vexide::main]
     /// async fn main(peripherals: Peripherals) {
-    ///     let motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
-    ///     let _ = motor.set_voltage(motor.max_voltage())
+    ///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///     let _ = motor.set_voltage(motor.max_voltage());
     ///     loop {
-    ///         println!("Efficiency: {:.2}", motor.efficiency().unwrap());
+    ///         println!("Efficiency: {:.1}%", motor.efficiency().unwrap() * 100.0);
     ///         sleep(Motor::UPDATE_INTERVAL).await;
     ///     }
     /// }
@@ -985,6 +1608,12 @@ impl Motor {
 
     /// Sets the current limit for the motor in amps.
     ///
+    /// # Persistence
+    ///
+    /// VEXos stores this limit along with the rest of the motor's configuration on the Brain, so it
+    /// remains in effect if the motor is disconnected and later reconnected. Limits are not saved
+    /// between program runs.
+    ///
     /// # Errors
     ///
     /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
@@ -1010,6 +1639,12 @@ impl Motor {
 
     /// Sets the voltage limit for the motor in volts.
     ///
+    /// # Persistence
+    ///
+    /// VEXos stores this limit along with the rest of the motor's configuration on the Brain, so it
+    /// remains in effect if the motor is disconnected and later reconnected. Limits are not saved
+    /// between program runs.
+    ///
     /// # Errors
     ///
     /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
@@ -1298,6 +1933,47 @@ impl Motor {
         Ok(self.faults()?.contains(MotorFaults::OVER_CURRENT))
     }
 
+    /// Returns a snapshot of the motor's state for diagnosing problems.
+    ///
+    /// This reads every value in [`MotorDiagnostic`] at once. The Brain only receives new data
+    /// from the motor every [`Motor::UPDATE_INTERVAL`], and no other tasks run while this function
+    /// is reading, so every value in the snapshot comes from the same update.
+    ///
+    /// # Errors
+    ///
+    /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// Log the state of a motor during a match:
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///     loop {
+    ///         if let Ok(diagnostic) = motor.diagnostic() {
+    ///             println!("{diagnostic:?}");
+    ///         }
+    ///         sleep(Motor::UPDATE_INTERVAL).await;
+    ///     }
+    /// }
+    /// ```
+    pub fn diagnostic(&self) -> Result<MotorDiagnostic, MotorError> {
+        Ok(MotorDiagnostic {
+            timestamp: self.timestamp()?,
+            position: self.position()?,
+            velocity: self.velocity()?,
+            voltage: self.voltage()?,
+            current: self.current()?,
+            temperature: self.temperature()?,
+            efficiency: self.efficiency()?,
+            faults: self.faults()?,
+        })
+    }
+
     /// Sets the motor to operate in a given [`Direction`].
     ///
     /// This determines which way the motor considers to be “forwards”. You can use the marking on the back of the
@@ -1360,6 +2036,79 @@ impl Motor {
         })
     }
 
+    /// Sets whether the motor is reversed.
+    ///
+    /// This is a shorthand for calling [`Motor::set_direction`] with [`Direction::Reverse`] if
+    /// `reversed` is `true`, or [`Direction::Forward`] if `reversed` is `false`.
+    ///
+    /// # Errors
+    ///
+    /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///     motor.set_reversed(true).unwrap();
+    /// }
+    /// ```
+    pub fn set_reversed(&mut self, reversed: bool) -> Result<(), MotorError> {
+        self.set_direction(if reversed {
+            Direction::Reverse
+        } else {
+            Direction::Forward
+        })
+    }
+
+    /// Returns `true` if the motor is set to [`Direction::Reverse`].
+    ///
+    /// # Errors
+    ///
+    /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// fn print_motor_reversed(motor: &Motor) {
+    ///     if motor.is_reversed().unwrap() {
+    ///         println!("Motor is reversed");
+    ///     }
+    /// }
+    /// ```
+    pub fn is_reversed(&self) -> Result<bool, MotorError> {
+        Ok(self.direction()?.is_reverse())
+    }
+
+    /// Flips the motor's [`Direction`], making forwards become reverse and vice versa.
+    ///
+    /// # Errors
+    ///
+    /// - A [`MotorError::Port`] error is returned if a motor device is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+    ///
+    ///     // The motor is now reversed.
+    ///     motor.reverse().unwrap();
+    /// }
+    /// ```
+    pub fn reverse(&mut self) -> Result<(), MotorError> {
+        let direction = self.direction()?;
+        self.set_direction(!direction)
+    }
+
     /// Adjusts the internal tuning constants of the motor when using velocity control.
     ///
     /// # Hardware Safety
@@ -1404,12 +2153,27 @@ impl Motor {
     ) -> Result<(), MotorError> {
         self.validate_port()?;
 
+        self.velocity_tuning_constants = Some(constants);
+
         let mut constants = V5_DeviceMotorPid::from(constants);
         unsafe { vexDeviceMotorVelocityPidSet(self.device, &mut constants) }
 
         Ok(())
     }
 
+    /// Returns the velocity control tuning constants most recently set with
+    /// [`Motor::set_velocity_tuning_constants`].
+    ///
+    /// VEXos provides no way to read tuning constants back from the motor, so this returns `None`
+    /// if the constants have not been changed through this `Motor`, in which case the motor is
+    /// using its default (undisclosed) constants. Constants set by another program or before the
+    /// motor was power cycled are not reflected here.
+    #[cfg(feature = "dangerous_motor_tuning")]
+    #[must_use]
+    pub const fn velocity_tuning_constants(&self) -> Option<MotorTuningConstants> {
+        self.velocity_tuning_constants
+    }
+
     /// Adjusts the internal tuning constants of the motor when using position control.
     ///
     /// # Hardware Safety
@@ -1454,11 +2218,26 @@ impl Motor {
     ) -> Result<(), MotorError> {
         self.validate_port()?;
 
+        self.position_tuning_constants = Some(constants);
+
         let mut constants = V5_DeviceMotorPid::from(constants);
         unsafe { vexDeviceMotorPositionPidSet(self.device, &mut constants) }
 
         Ok(())
     }
+
+    /// Returns the position control tuning constants most recently set with
+    /// [`Motor::set_position_tuning_constants`].
+    ///
+    /// VEXos provides no way to read tuning constants back from the motor, so this returns `None`
+    /// if the constants have not been changed through this `Motor`, in which case the motor is
+    /// using its default (undisclosed) constants. Constants set by another program or before the
+    /// motor was power cycled are not reflected here.
+    #[cfg(feature = "dangerous_motor_tuning")]
+    #[must_use]
+    pub const fn position_tuning_constants(&self) -> Option<MotorTuningConstants> {
+        self.position_tuning_constants
+    }
 }
 
 impl SmartDevice for Motor {
@@ -1476,8 +2255,109 @@ impl From<Motor> for SmartPort {
     }
 }
 
+/// A future that waits for a [`Motor`] to reach a position.
+///
+/// This future is returned by [`Motor::wait_until_position`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct MotorPositionFuture<'a> {
+    motor: &'a Motor,
+    target: Position,
+    tolerance: Position,
+    next_update: Instant,
+}
+
+impl Future for MotorPositionFuture<'_> {
+    type Output = Result<(), MotorError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let now = Instant::now();
+
+        if now >= self.next_update {
+            let position = match self.motor.position() {
+                Ok(position) => position,
+                Err(err) => return Poll::Ready(Err(err)),
+            };
+
+            if (position - self.target).as_degrees().abs() <= self.tolerance.as_degrees() {
+                return Poll::Ready(Ok(()));
+            }
+
+            self.next_update = now + Motor::UPDATE_INTERVAL;
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// A future that coasts a [`Motor`] to a stop at a position.
+///
+/// This future is returned by [`Motor::coast_to_position`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct MotorCoastFuture<'a> {
+    motor: &'a mut Motor,
+    target: Position,
+    deceleration: f64,
+    next_update: Instant,
+}
+
+impl Future for MotorCoastFuture<'_> {
+    type Output = Result<(), MotorError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let now = Instant::now();
+
+        if now >= self.next_update {
+            let (position, velocity) = match (self.motor.position(), self.motor.velocity()) {
+                (Ok(position), Ok(velocity)) => (position, velocity),
+                (Err(err), _) | (_, Err(err)) => return Poll::Ready(Err(err)),
+            };
+
+            // Distance left to travel in the direction that the motor is moving.
+            let mut remaining = (self.target - position).as_revolutions();
+            if velocity < 0.0 {
+                remaining = -remaining;
+            }
+
+            // With velocity in RPM and deceleration in RPM/s, the distance covered while
+            // decelerating to a stop is v^2 / 2a, divided by 60 to convert to revolutions.
+            let stopping_distance = velocity * velocity / (120.0 * self.deceleration);
+
+            if remaining <= stopping_distance {
+                return Poll::Ready(self.motor.brake(BrakeMode::Coast));
+            }
+
+            self.next_update = now + Motor::UPDATE_INTERVAL;
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
 /// Determines the behavior a motor should use when braking with [`Motor::brake`].
+///
+/// Brake modes can be converted to and from their lowercase names (`"coast"`, `"brake"`, and
+/// `"hold"`) using [`FromStr`] and [`Display`](fmt::Display), which is useful when loading robot
+/// configuration from a file. When the `serde` feature is enabled, brake modes are serialized
+/// using these same names.
+///
+/// # Examples
+///
+/// ```
+/// use vexide::prelude::*;
+///
+/// let mode: BrakeMode = "hold".parse().unwrap();
+/// assert_eq!(mode, BrakeMode::Hold);
+///
+/// println!("Brake mode: {mode}"); // Prints "Brake mode: hold"
+/// assert!("sideways".parse::<BrakeMode>().is_err());
+/// ```
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum BrakeMode {
     /// Motor never brakes.
     Coast,
@@ -1510,6 +2390,45 @@ impl From<BrakeMode> for V5MotorBrakeMode {
     }
 }
 
+impl fmt::Display for BrakeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Coast => "coast",
+            Self::Brake => "brake",
+            Self::Hold => "hold",
+        })
+    }
+}
+
+impl FromStr for BrakeMode {
+    type Err = ParseBrakeModeError;
+
+    /// Parses a brake mode from its name, ignoring ASCII case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("coast") {
+            Ok(Self::Coast)
+        } else if s.eq_ignore_ascii_case("brake") {
+            Ok(Self::Brake)
+        } else if s.eq_ignore_ascii_case("hold") {
+            Ok(Self::Hold)
+        } else {
+            Err(ParseBrakeModeError)
+        }
+    }
+}
+
+/// The error returned when parsing a [`BrakeMode`] from a string fails.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ParseBrakeModeError;
+
+impl fmt::Display for ParseBrakeModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown brake mode (expected `coast`, `brake`, or `hold`)")
+    }
+}
+
+impl core::error::Error for ParseBrakeModeError {}
+
 bitflags! {
     /// The fault flags returned by a [`Motor`].
     #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -1528,6 +2447,34 @@ bitflags! {
     }
 }
 
+/// A snapshot of a [`Motor`]'s state, returned by [`Motor::diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotorDiagnostic {
+    /// The timestamp of the motor's internal clock when this data was recorded.
+    pub timestamp: SmartDeviceTimestamp,
+
+    /// The position of the motor. See [`Motor::position`].
+    pub position: Position,
+
+    /// The velocity of the motor in RPM. See [`Motor::velocity`].
+    pub velocity: f64,
+
+    /// The voltage the motor is drawing in volts. See [`Motor::voltage`].
+    pub voltage: f64,
+
+    /// The current drawn by the motor in amps. See [`Motor::current`].
+    pub current: f64,
+
+    /// The internal temperature of the motor in degrees Celsius. See [`Motor::temperature`].
+    pub temperature: f64,
+
+    /// The efficiency of the motor from 0.0 to 1.0. See [`Motor::efficiency`].
+    pub efficiency: f64,
+
+    /// The fault flags reported by the motor. See [`Motor::faults`].
+    pub faults: MotorFaults,
+}
+
 bitflags! {
     /// The status bits returned by a [`Motor`].
     #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -1578,6 +2525,9 @@ impl Gearset {
     pub const BLUE_TICKS_PER_REVOLUTION: u32 = 300;
 
     /// Returns the rated maximum speed for this motor gearset.
+    ///
+    /// This is the free speed of the motor's output shaft with no load applied.
+    #[doc(alias = "free_speed_rpm")]
     #[must_use]
     pub const fn max_rpm(&self) -> f64 {
         match self {
@@ -1587,6 +2537,33 @@ impl Gearset {
         }
     }
 
+    /// Returns the reduction ratio of this motor gearset.
+    ///
+    /// This is the number of revolutions of the motor's internal rotor for each revolution of the
+    /// output shaft. All gearsets share the same rotor, so multiplying this by
+    /// [`Gearset::max_rpm`] is the same for every gearset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// // A 200 RPM motor driving a 36 tooth gear from a 60 tooth gear.
+    /// let gearset = Gearset::Green;
+    /// let wheel_rpm = gearset.max_rpm() * 36.0 / 60.0;
+    ///
+    /// println!("Total reduction: {}:1", gearset.reduction_ratio() * 60.0 / 36.0);
+    /// println!("Wheel free speed: {wheel_rpm} RPM");
+    /// ```
+    #[must_use]
+    pub const fn reduction_ratio(&self) -> f64 {
+        match self {
+            Self::Red => 36.0,
+            Self::Green => 18.0,
+            Self::Blue => 6.0,
+        }
+    }
+
     /// Returns the number of encoder ticks per revolution for this motor gearset.
     #[must_use]
     pub const fn ticks_per_revolution(&self) -> u32 {
@@ -20,8 +20,14 @@
 //! The optical sensor can detect four distinct motions (up, down, left, right) of objects
 //! passing over the sensor.
 
-use core::time::Duration;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
 
+use snafu::Snafu;
 use vex_sdk::{
     vexDeviceOpticalBrightnessGet, vexDeviceOpticalGestureEnable, vexDeviceOpticalGestureGet,
     vexDeviceOpticalHueGet, vexDeviceOpticalIntegrationTimeGet, vexDeviceOpticalIntegrationTimeSet,
@@ -30,6 +36,7 @@ use vex_sdk::{
     vexDeviceOpticalStatusGet, V5_DeviceOpticalGesture, V5_DeviceOpticalRaw, V5_DeviceOpticalRgb,
     V5_DeviceT,
 };
+use vexide_core::time::Instant;
 
 use super::{SmartDevice, SmartDeviceTimestamp, SmartDeviceType, SmartPort};
 use crate::PortError;
@@ -109,7 +116,7 @@ impl OpticalSensor {
 
     /// Set the intensity of (intensity/brightness) of the sensor's LED indicator.
     ///
-    /// Intensity is expressed as a number from [0.0, 1.0].
+    /// Intensity is expressed as a number from [0.0, 1.0]. Values outside of this range will be clamped.
     ///
     /// # Errors
     ///
@@ -146,16 +153,65 @@ impl OpticalSensor {
     pub fn set_led_brightness(&mut self, brightness: f64) -> Result<(), PortError> {
         self.validate_port()?;
 
-        unsafe { vexDeviceOpticalLedPwmSet(self.device, (brightness * 100.0) as i32) }
+        unsafe {
+            vexDeviceOpticalLedPwmSet(self.device, (brightness.clamp(0.0, 1.0) * 100.0) as i32);
+        }
 
         Ok(())
     }
 
-    /// Returns integration time of the optical sensor in milliseconds, with
+    /// Turns the sensor's LED on at full brightness.
+    ///
+    /// This is equivalent to calling [`OpticalSensor::set_led_brightness`] with a value of `1.0`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if an optical sensor is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut sensor = OpticalSensor::new(peripherals.port_1);
+    ///     _ = sensor.enable_led();
+    /// }
+    /// ```
+    pub fn enable_led(&mut self) -> Result<(), PortError> {
+        self.set_led_brightness(1.0)
+    }
+
+    /// Turns the sensor's LED off.
+    ///
+    /// This is equivalent to calling [`OpticalSensor::set_led_brightness`] with a value of `0.0`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if an optical sensor is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut sensor = OpticalSensor::new(peripherals.port_1);
+    ///     _ = sensor.disable_led();
+    /// }
+    /// ```
+    pub fn disable_led(&mut self) -> Result<(), PortError> {
+        self.set_led_brightness(0.0)
+    }
+
+    /// Returns the integration time (camera exposure) of the optical sensor, with
     /// minimum time being 3ms and the maximum time being 712ms.
     ///
     /// The default integration time for the sensor is 103mS, unless otherwise set with
-    /// [`OpticalSensor::set_integration_time`].
+    /// [`OpticalSensor::set_integration_time`]. The sensor reports this value with sub-millisecond
+    /// precision, which is preserved in the returned [`Duration`].
     ///
     /// # Errors
     ///
@@ -183,15 +239,17 @@ impl OpticalSensor {
     pub fn integration_time(&self) -> Result<Duration, PortError> {
         self.validate_port()?;
 
-        Ok(Duration::from_millis(
-            unsafe { vexDeviceOpticalIntegrationTimeGet(self.device) } as u64,
+        Ok(Duration::from_micros(
+            (unsafe { vexDeviceOpticalIntegrationTimeGet(self.device) } * 1000.0) as u64,
         ))
     }
 
-    /// Set the integration time of the optical sensor.
+    /// Set the integration time (camera exposure) of the optical sensor.
     ///
     /// Lower integration time results in faster update rates with lower accuracy
-    /// due to less available light being read by the sensor.
+    /// due to less available light being read by the sensor. Conversely, a longer
+    /// integration time improves color accuracy in dim lighting at the cost of
+    /// slower updates.
     ///
     /// The `time` value must be a [`Duration`] between 3 and 712 milliseconds. If
     /// the integration time is out of this range, it will be clamped to fit inside it. See
@@ -381,6 +439,48 @@ impl OpticalSensor {
         Ok(f64::from(unsafe { vexDeviceOpticalProximityGet(self.device) }) / 255.0)
     }
 
+    /// Waits until the [proximity](OpticalSensor::proximity) reading reaches `threshold`, returning
+    /// the reading that met it.
+    ///
+    /// This is useful for detecting when a game element has entered an intake without
+    /// continuously polling the sensor. The returned future completes as soon as a reading
+    /// greater than or equal to `threshold` is seen.
+    ///
+    /// # Errors
+    ///
+    /// The returned future will resolve to:
+    ///
+    /// - An [`OpticalError::ProximityTimedOut`] error if `timeout` elapses before the threshold is reached.
+    /// - An [`OpticalError::Port`] error if an optical sensor is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let sensor = OpticalSensor::new(peripherals.port_1);
+    ///
+    ///     match sensor.wait_for_proximity(0.5, Duration::from_secs(2)).await {
+    ///         Ok(prox) => println!("Object detected with proximity {prox}"),
+    ///         Err(err) => println!("No object detected: {err}"),
+    ///     }
+    /// }
+    /// ```
+    pub fn wait_for_proximity(
+        &self,
+        threshold: f64,
+        timeout: Duration,
+    ) -> OpticalProximityFuture<'_> {
+        OpticalProximityFuture {
+            sensor: self,
+            threshold,
+            deadline: Instant::now() + timeout,
+        }
+    }
+
     /// Returns the processed RGB color data from the sensor.
     ///
     /// # Errors
@@ -419,9 +519,50 @@ impl OpticalSensor {
 
     /// Returns the raw, unprocessed RGBC color data from the sensor.
     ///
+    /// Unlike [`OpticalSensor::color`], these readings are the sensor's unscaled photodiode counts,
+    /// which makes them useful for calibrating custom color classification.
+    ///
+    /// # Channel Depth
+    ///
+    /// Each channel (red, green, blue, and clear) is a 16-bit count in the range [0, 65535]. The
+    /// counts accumulate over the sensor's [integration time](OpticalSensor::integration_time), so
+    /// longer integration times produce larger readings and will saturate sooner in bright
+    /// environments.
+    ///
+    /// # LED Brightness
+    ///
+    /// Light from the sensor's LED that is reflected off of an object is included in these readings.
+    /// Raising the [LED brightness](OpticalSensor::set_led_brightness) increases the counts on every
+    /// channel for nearby objects, so the LED brightness should be kept constant between calibrating
+    /// and using any thresholds derived from these values.
+    ///
     /// # Errors
     ///
     /// An error is returned if an optical sensor is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut sensor = OpticalSensor::new(peripherals.port_1);
+    ///     _ = sensor.enable_led();
+    ///
+    ///     loop {
+    ///         if let Ok(raw) = sensor.raw_color() {
+    ///             println!(
+    ///                 "R: {}, G: {}, B: {}, C: {}",
+    ///                 raw.red, raw.green, raw.blue, raw.clear
+    ///             );
+    ///         }
+    ///
+    ///         sleep(OpticalSensor::UPDATE_INTERVAL).await;
+    ///     }
+    /// }
+    /// ```
+    #[doc(alias = "raw_rgb")]
     pub fn raw_color(&self) -> Result<OpticalRaw, PortError> {
         self.validate_port()?;
 
@@ -536,6 +677,37 @@ impl From<OpticalSensor> for SmartPort {
     }
 }
 
+/// A future that waits for an [`OpticalSensor`]'s proximity reading to reach a threshold.
+///
+/// This future is returned by [`OpticalSensor::wait_for_proximity`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct OpticalProximityFuture<'a> {
+    sensor: &'a OpticalSensor,
+    threshold: f64,
+    deadline: Instant,
+}
+
+impl Future for OpticalProximityFuture<'_> {
+    type Output = Result<f64, OpticalError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let proximity = match self.sensor.proximity() {
+            Ok(proximity) => proximity,
+            Err(err) => return Poll::Ready(Err(err.into())),
+        };
+
+        if proximity >= self.threshold {
+            Poll::Ready(Ok(proximity))
+        } else if Instant::now() >= self.deadline {
+            Poll::Ready(Err(OpticalError::ProximityTimedOut))
+        } else {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
 /// Represents a gesture and its direction.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum GestureDirection {
@@ -617,3 +789,16 @@ impl From<V5_DeviceOpticalRaw> for OpticalRaw {
         }
     }
 }
+
+/// Errors that can occur when waiting on an [`OpticalSensor`].
+#[derive(Debug, Snafu)]
+pub enum OpticalError {
+    /// The proximity threshold was not reached before the timeout elapsed.
+    ProximityTimedOut,
+    /// Generic port related error.
+    #[snafu(transparent)]
+    Port {
+        /// The source of the error.
+        source: PortError,
+    },
+}
@@ -333,7 +333,10 @@ impl RotationSensor {
 
     /// Returns the angle of rotation measured by the sensor.
     ///
-    /// This value is reported from 0-360 degrees.
+    /// This value is reported from 0-360 degrees, not including 360. Internally, the sensor
+    /// measures its angle in centidegrees (hundredths of a degree) from 0 to 35999, which is
+    /// where [`RotationSensor::TICKS_PER_REVOLUTION`] comes from. The returned [`Position`] can
+    /// be converted to degrees, radians, or centidegrees as shown below.
     ///
     /// # Errors
     ///
@@ -350,18 +353,22 @@ impl RotationSensor {
     ///
     ///     if let Ok(angle) = sensor.angle() {
     ///         println!("Angle in degrees: {}°", angle.as_degrees());
-    ///         println!("Angle in radians: {}°", angle.as_radians());
-    ///         println!("Angle in raw ticks (centidegrees): {}°", angle.as_ticks(RotationSensor::TICKS_PER_REVOLUTION));
+    ///         println!("Angle in radians: {}", angle.as_radians());
+    ///         println!("Angle in raw ticks (centidegrees): {}", angle.as_ticks(RotationSensor::TICKS_PER_REVOLUTION));
     ///     }
     /// }
     /// ```
+    #[doc(alias = "angle_centidegrees")]
+    #[doc(alias = "angle_radians")]
     pub fn angle(&self) -> Result<Position, PortError> {
         self.validate_port()?;
 
         let mut raw_angle = unsafe { vexDeviceAbsEncAngleGet(self.device) };
 
         if self.direction == Direction::Reverse {
-            raw_angle = (Self::TICKS_PER_REVOLUTION as i32) - raw_angle;
+            // An angle of zero should stay zero rather than wrapping to a full revolution.
+            raw_angle = ((Self::TICKS_PER_REVOLUTION as i32) - raw_angle)
+                % (Self::TICKS_PER_REVOLUTION as i32);
         }
 
         Ok(Position::from_ticks(
@@ -372,10 +379,17 @@ impl RotationSensor {
 
     /// Returns the sensor's current velocity in degrees per second.
     ///
+    /// This velocity is computed by the sensor's firmware from its internal high-resolution
+    /// position readings, so it will generally be less noisy than differencing the values
+    /// returned by [`RotationSensor::position`] in user code. Like [`RotationSensor::position`],
+    /// the sign of this value is flipped if the sensor's [`Direction`] is reversed.
+    ///
     /// # Errors
     ///
     /// An error is returned if an rotation sensor is not currently connected to the Smart Port.
     ///
+    /// # Examples
+    ///
     /// ```
     /// use vexide::prelude::*;
     ///
@@ -383,11 +397,10 @@ impl RotationSensor {
     /// async fn main(peripherals: Peripherals) {
     ///     let sensor = RotationSensor::new(peripherals.port_1, Direction::Forward);
     ///
-    ///     if let Some(velocity) = sensor.velocity() {
-    ///         println!(
-    ///             "Velocity in RPM {}",
-    ///             velocity / 6.0, // 1rpm = 6dps
-    ///         );
+    ///     if let Ok(velocity) = sensor.velocity() {
+    ///         println!("Velocity in degrees per second: {}", velocity);
+    ///         println!("Velocity in radians per second: {}", velocity.to_radians());
+    ///         println!("Velocity in RPM: {}", velocity / 6.0); // 1rpm = 6dps
     ///     }
     /// }
     /// ```
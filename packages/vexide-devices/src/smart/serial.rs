@@ -14,7 +14,7 @@
 //! but are automatically flushed by VEXos as fast as possible (down to ~10µs or so).
 
 use no_std_io::io;
-use snafu::Snafu;
+use snafu::{ensure, Snafu};
 use vex_sdk::{
     vexDeviceGenericSerialBaudrate, vexDeviceGenericSerialEnable, vexDeviceGenericSerialFlush,
     vexDeviceGenericSerialPeekChar, vexDeviceGenericSerialReadChar, vexDeviceGenericSerialReceive,
@@ -36,6 +36,7 @@ use crate::PortError;
 pub struct SerialPort {
     port: SmartPort,
     device: V5_DeviceT,
+    baud_rate: u32,
 }
 
 // SAFETY: Required because we store a raw pointer to the device handle to avoid it getting from the
@@ -80,7 +81,11 @@ impl SerialPort {
             vexDeviceGenericSerialBaudrate(device, baud_rate as i32);
         }
 
-        Self { port, device }
+        Self {
+            port,
+            device,
+            baud_rate,
+        }
     }
 
     /// Configures the baud rate of the serial port.
@@ -90,6 +95,7 @@ impl SerialPort {
     ///
     /// # Errors
     ///
+    /// - A [`SerialError::InvalidBaudRate`] error is returned if `baud_rate` is zero or greater than [`SerialPort::MAX_BAUD_RATE`].
     /// - A [`SerialError::Port`] error is returned if a generic serial device is not currently connected to the Smart Port.
     ///
     /// # Examples
@@ -106,15 +112,43 @@ impl SerialPort {
     /// }
     /// ```
     pub fn set_baud_rate(&mut self, baud_rate: u32) -> Result<(), SerialError> {
+        ensure!(
+            baud_rate > 0 && baud_rate <= Self::MAX_BAUD_RATE,
+            InvalidBaudRateSnafu { baud_rate }
+        );
         self.validate_port()?;
 
         unsafe {
             vexDeviceGenericSerialBaudrate(self.device, baud_rate as i32);
         }
 
+        self.baud_rate = baud_rate;
+
         Ok(())
     }
 
+    /// Returns the baud rate that the serial port was last configured to use.
+    ///
+    /// VEXos does not provide a way to read the baud rate back from the port, so this is the value
+    /// passed to [`SerialPort::open`] or the last successful call to [`SerialPort::set_baud_rate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let serial = SerialPort::open(peripherals.port_1, 115200);
+    ///
+    ///     assert_eq!(serial.baud_rate(), 115200);
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn baud_rate(&self) -> u32 {
+        self.baud_rate
+    }
+
     /// Clears the internal input and output FIFO buffers.
     ///
     /// This can be useful to reset state and remove old, potentially unneeded data
@@ -438,6 +472,13 @@ pub enum SerialError {
     /// Internal read error occurred.
     ReadFailed,
 
+    /// The requested baud rate is not supported.
+    #[snafu(display("Unsupported baud rate of {baud_rate} (must be between 1 and 921600)."))]
+    InvalidBaudRate {
+        /// The requested baud rate.
+        baud_rate: u32,
+    },
+
     /// Generic port related error.
     #[snafu(transparent)]
     Port {
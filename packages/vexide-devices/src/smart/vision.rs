@@ -21,8 +21,8 @@
 
 extern crate alloc;
 
-use alloc::vec::Vec;
-use core::time::Duration;
+use alloc::{format, vec::Vec};
+use core::{str::FromStr, time::Duration};
 
 use snafu::{ensure, Snafu};
 use vex_sdk::{
@@ -36,9 +36,10 @@ use vex_sdk::{
     V5VisionWifiMode, V5_DeviceT, V5_DeviceVisionObject, V5_DeviceVisionRgb,
     V5_DeviceVisionSignature,
 };
+use vexide_core::{fs, io, path::Path};
 
 use super::{SmartDevice, SmartDeviceType, SmartPort};
-use crate::{math::Point2, rgb::Rgb, PortError};
+use crate::{display::Rect, math::Point2, rgb::Rgb, PortError};
 
 /// VEX Vision Sensor
 ///
@@ -47,6 +48,7 @@ use crate::{math::Point2, rgb::Rgb, PortError};
 pub struct VisionSensor {
     port: SmartPort,
     codes: Vec<VisionCode>,
+    zero_point: VisionZeroPoint,
     device: V5_DeviceT,
 }
 
@@ -96,6 +98,7 @@ impl VisionSensor {
             device: unsafe { port.device_handle() },
             port,
             codes: Vec::new(),
+            zero_point: VisionZeroPoint::TopLeft,
         }
     }
 
@@ -645,6 +648,10 @@ impl VisionSensor {
 
     /// Returns a [`Vec`] of objects detected by the sensor.
     ///
+    /// Object coordinates are relative to the sensor's current [zero point].
+    ///
+    /// [zero point]: VisionSensor::set_zero_point
+    ///
     /// # Errors
     ///
     /// - A [`VisionError::Port`] error is returned if a vision sensor is not currently connected to the Smart Port.
@@ -710,6 +717,47 @@ impl VisionSensor {
     /// }
     /// ```
     pub fn objects(&self) -> Result<Vec<VisionObject>, VisionError> {
+        self.objects_matching(|_| true)
+    }
+
+    /// Returns a [`Vec`] of objects detected by the sensor using the signature with the given ID.
+    ///
+    /// Objects detected by [`VisionCode`]s or line detection are not included, even if the code
+    /// contains the signature.
+    ///
+    /// # Errors
+    ///
+    /// - A [`VisionError::Port`] error is returned if a vision sensor is not currently connected to the Smart Port.
+    /// - A [`VisionError::WifiMode`] error is returned if the vision sensor is in Wi-Fi mode.
+    /// - A [`VisionError::ReadingFailed`] error if the objects could not be read from the sensor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut sensor = VisionSensor::new(peripherals.port_1);
+    ///
+    ///     _ = sensor.set_signature(1, VisionSignature::new((10049, 11513, 10781), (-425, 1, -212), 4.1));
+    ///     _ = sensor.set_signature(2, VisionSignature::new((8973, 11143, 10058), (-2119, -1053, -1586), 5.4));
+    ///
+    ///     // Only look for objects matching the second signature.
+    ///     if let Ok(objects) = sensor.objects_in_signature(2) {
+    ///         println!("Found {} objects matching signature 2", objects.len());
+    ///     }
+    /// }
+    /// ```
+    pub fn objects_in_signature(&self, id: u8) -> Result<Vec<VisionObject>, VisionError> {
+        self.objects_matching(|object| object.source == DetectionSource::Signature(id))
+    }
+
+    /// Reads the objects detected by the sensor, keeping only those accepted by `filter`.
+    fn objects_matching(
+        &self,
+        filter: impl Fn(&VisionObject) -> bool,
+    ) -> Result<Vec<VisionObject>, VisionError> {
         ensure!(self.mode()? != VisionMode::Wifi, WifiModeSnafu);
 
         let object_count = unsafe { vexDeviceVisionObjectCountGet(self.device) } as usize;
@@ -722,7 +770,21 @@ impl VisionSensor {
                 return ReadingFailedSnafu.fail();
             }
 
-            let object: VisionObject = object.into();
+            let mut object: VisionObject = object.into();
+
+            if !filter(&object) {
+                continue;
+            }
+
+            if self.zero_point == VisionZeroPoint::Center {
+                let origin_x = (Self::HORIZONTAL_RESOLUTION / 2) as i16;
+                let origin_y = (Self::VERTICAL_RESOLUTION / 2) as i16;
+
+                object.offset.x -= origin_x;
+                object.offset.y -= origin_y;
+                object.center.x -= origin_x;
+                object.center.y -= origin_y;
+            }
 
             match object.source {
                 DetectionSource::Signature(_) | DetectionSource::Line => {
@@ -872,6 +934,133 @@ impl VisionSensor {
 
         Ok(unsafe { vexDeviceVisionModeGet(self.device) }.into())
     }
+
+    /// Enables or disables the sensor's onboard Wi-Fi hotspot.
+    ///
+    /// Enabling Wi-Fi is equivalent to setting the sensor to [`VisionMode::Wifi`], and disables
+    /// object detection. Disabling Wi-Fi leaves the sensor's detection mode unchanged, which may
+    /// be useful for reducing interference from the sensor's antenna on nearby devices.
+    ///
+    /// # Errors
+    ///
+    /// - A [`VisionError::Port`] error is returned if a vision sensor is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut sensor = VisionSensor::new(peripherals.port_1);
+    ///
+    ///     // Make sure the Wi-Fi hotspot is off.
+    ///     _ = sensor.set_wifi_enabled(false);
+    /// }
+    /// ```
+    pub fn set_wifi_enabled(&mut self, enabled: bool) -> Result<(), VisionError> {
+        self.validate_port()?;
+
+        unsafe {
+            vexDeviceVisionWifiModeSet(
+                self.device,
+                if enabled {
+                    V5VisionWifiMode::kVisionWifiModeOn
+                } else {
+                    V5VisionWifiMode::kVisionWifiModeOff
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the sensor's onboard Wi-Fi hotspot is enabled.
+    ///
+    /// # Errors
+    ///
+    /// - A [`VisionError::Port`] error is returned if a vision sensor is not currently connected to the Smart Port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let sensor = VisionSensor::new(peripherals.port_1);
+    ///
+    ///     if sensor.is_wifi_enabled().unwrap_or_default() {
+    ///         println!("Vision sensor hotspot is on.");
+    ///     }
+    /// }
+    /// ```
+    pub fn is_wifi_enabled(&self) -> Result<bool, VisionError> {
+        self.validate_port()?;
+
+        Ok(unsafe { vexDeviceVisionWifiModeGet(self.device) }
+            == V5VisionWifiMode::kVisionWifiModeOn)
+    }
+
+    /// Sets the origin of the coordinate system used for detected objects.
+    ///
+    /// This changes the [`offset`] and [`center`] of every [`VisionObject`] returned by
+    /// subsequent calls to [`VisionSensor::objects`]. Objects that were already returned
+    /// are not modified. By default, coordinates are relative to the top-left of the camera's
+    /// field of view. See [`VisionZeroPoint`] for more information on each option.
+    ///
+    /// Unlike most other settings, the zero point is not stored on the sensor itself. Coordinates
+    /// are adjusted by vexide after they are read, so this setting is not shared with other
+    /// [`VisionSensor`] instances and does not require the sensor to be connected.
+    ///
+    /// [`offset`]: VisionObject::offset
+    /// [`center`]: VisionObject::center
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut sensor = VisionSensor::new(peripherals.port_1);
+    ///
+    ///     // Measure object positions from the middle of the camera's view.
+    ///     sensor.set_zero_point(VisionZeroPoint::Center);
+    ///
+    ///     if let Ok(objects) = sensor.objects() {
+    ///         for object in objects {
+    ///             if object.center.x < 0 {
+    ///                 println!("Object is to the left of the sensor.");
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub const fn set_zero_point(&mut self, zero_point: VisionZeroPoint) {
+        self.zero_point = zero_point;
+    }
+
+    /// Returns the origin of the coordinate system used for detected objects.
+    ///
+    /// See [`VisionSensor::set_zero_point`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let sensor = VisionSensor::new(peripherals.port_1);
+    ///
+    ///     assert_eq!(sensor.zero_point(), VisionZeroPoint::TopLeft);
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn zero_point(&self) -> VisionZeroPoint {
+        self.zero_point
+    }
 }
 
 impl SmartDevice for VisionSensor {
@@ -1001,6 +1190,87 @@ impl VisionSignature {
             flags: 0,
         }
     }
+
+    /// Saves this signature to a file, allowing it to be reloaded later using
+    /// [`VisionSignature::load_from_file`].
+    ///
+    /// Signatures are stored as a single line of text containing the U thresholds, V thresholds,
+    /// range, and flags separated by spaces (for example, `10049 11513 10781 -425 1 -212 4.1 0`).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the file could not be written to. See [`fs::write`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::devices::smart::vision::VisionSignature;
+    ///
+    /// let signature = VisionSignature::new((10049, 11513, 10781), (-425, 1, -212), 4.1);
+    /// signature.save_to_file("red.sig").unwrap();
+    /// ```
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let (u_min, u_max, u_mean) = self.u_threshold;
+        let (v_min, v_max, v_mean) = self.v_threshold;
+
+        fs::write(
+            path,
+            format!(
+                "{u_min} {u_max} {u_mean} {v_min} {v_max} {v_mean} {} {}\n",
+                self.range, self.flags
+            ),
+        )
+    }
+
+    /// Loads a signature from a file created by [`VisionSignature::save_to_file`].
+    ///
+    /// # Errors
+    ///
+    /// - An error is returned if the file could not be read. See [`fs::read_to_string`] for more information.
+    /// - An [`io::ErrorKind::InvalidData`] error is returned if the file does not contain a valid signature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut sensor = VisionSensor::new(peripherals.port_1);
+    ///
+    ///     if let Ok(signature) = VisionSignature::load_from_file("red.sig") {
+    ///         _ = sensor.set_signature(1, signature);
+    ///     }
+    /// }
+    /// ```
+    pub fn load_from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        fn field<T: FromStr>(fields: &mut core::str::SplitWhitespace<'_>) -> io::Result<T> {
+            fields
+                .next()
+                .and_then(|field| field.parse().ok())
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "Invalid vision signature file.")
+                })
+        }
+
+        let contents = fs::read_to_string(path)?;
+        let mut fields = contents.split_whitespace();
+
+        Ok(Self {
+            u_threshold: (
+                field(&mut fields)?,
+                field(&mut fields)?,
+                field(&mut fields)?,
+            ),
+            v_threshold: (
+                field(&mut fields)?,
+                field(&mut fields)?,
+                field(&mut fields)?,
+            ),
+            range: field(&mut fields)?,
+            flags: field(&mut fields)?,
+        })
+    }
 }
 
 impl From<V5_DeviceVisionSignature> for VisionSignature {
@@ -1250,6 +1520,25 @@ impl From<V5VisionMode> for VisionMode {
     }
 }
 
+/// The origin of the coordinate system used for [`VisionObject`]s.
+///
+/// See [`VisionSensor::set_zero_point`] for more information.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VisionZeroPoint {
+    /// Coordinates are relative to the top-left corner of the camera's field of view.
+    ///
+    /// X values increase to the right and Y values increase downwards, ranging from `0` to
+    /// [`VisionSensor::HORIZONTAL_RESOLUTION`] and [`VisionSensor::VERTICAL_RESOLUTION`].
+    #[default]
+    TopLeft,
+
+    /// Coordinates are relative to the center of the camera's field of view.
+    ///
+    /// X values increase to the right and Y values increase downwards, but objects to the
+    /// left of or above the center of the image will have negative coordinates.
+    Center,
+}
+
 /// Defines a source for what method was used to detect a [`VisionObject`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DetectionSource {
@@ -1279,18 +1568,68 @@ pub struct VisionObject {
     /// The height of the detected object's bounding box in pixels.
     pub height: u16,
 
-    /// The top-left coordinate of the detected object relative to the top-left
-    /// of the camera's field of view.
-    pub offset: Point2<u16>,
+    /// The top-left coordinate of the detected object relative to the sensor's
+    /// [zero point](VisionSensor::set_zero_point).
+    pub offset: Point2<i16>,
 
-    /// The center coordinate of the detected object relative to the top-left
-    /// of the camera's field of view.
-    pub center: Point2<u16>,
+    /// The center coordinate of the detected object relative to the sensor's
+    /// [zero point](VisionSensor::set_zero_point).
+    pub center: Point2<i16>,
 
     /// The approximate degrees of rotation of the detected object's bounding box.
     pub angle: u16,
 }
 
+impl VisionObject {
+    /// Returns the bounding box of the detected object as a [`Rect`] that can be drawn to the
+    /// [`Display`](crate::display::Display).
+    ///
+    /// The sensor's field of view is mapped onto the top-left [`VisionSensor::HORIZONTAL_RESOLUTION`]
+    /// by [`VisionSensor::VERTICAL_RESOLUTION`] pixels of the display, so the returned rectangle is
+    /// always relative to the top-left corner regardless of the sensor's zero point. `zero_point`
+    /// should be the [zero point](VisionSensor::zero_point) that the sensor was using when this
+    /// object was read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide::prelude::*;
+    ///
+    /// #[vexide::main]
+    /// async fn main(peripherals: Peripherals) {
+    ///     let mut display = peripherals.display;
+    ///     let sensor = VisionSensor::new(peripherals.port_1);
+    ///
+    ///     if let Ok(objects) = sensor.objects() {
+    ///         for object in objects {
+    ///             display.stroke(&object.bounding_box(sensor.zero_point()), Rgb::new(255, 0, 0));
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn bounding_box(&self, zero_point: VisionZeroPoint) -> Rect {
+        let mut start = self.offset;
+
+        if let VisionZeroPoint::Center = zero_point {
+            start.x += (VisionSensor::HORIZONTAL_RESOLUTION / 2) as i16;
+            start.y += (VisionSensor::VERTICAL_RESOLUTION / 2) as i16;
+        }
+
+        // Both corners of a `Rect` are inclusive, so the last pixel is one less than the size.
+        let width = if self.width > 0 { self.width - 1 } else { 0 };
+        let height = if self.height > 0 { self.height - 1 } else { 0 };
+
+        Rect {
+            start,
+            end: Point2 {
+                x: start.x + width as i16,
+                y: start.y + height as i16,
+            },
+        }
+    }
+}
+
 impl From<V5_DeviceVisionObject> for VisionObject {
     fn from(value: V5_DeviceVisionObject) -> Self {
         Self {
@@ -1307,12 +1646,12 @@ impl From<V5_DeviceVisionObject> for VisionObject {
             width: value.width,
             height: value.height,
             offset: Point2 {
-                x: value.xoffset,
-                y: value.yoffset,
+                x: value.xoffset as i16,
+                y: value.yoffset as i16,
             },
             center: Point2 {
-                x: value.xoffset + (value.width / 2),
-                y: value.yoffset + (value.height / 2),
+                x: (value.xoffset + (value.width / 2)) as i16,
+                y: (value.yoffset + (value.height / 2)) as i16,
             },
             angle: value.angle * 10,
         }
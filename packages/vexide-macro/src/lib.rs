@@ -84,10 +84,19 @@ fn make_entrypoint(inner: &ItemFn, opts: MacroOpts) -> proc_macro2::TokenStream
         quote! { false }
     };
 
+    // The hook is boxed by `set_hook`, so it can only be installed once the heap has been
+    // initialized by `startup`.
+    let panic_hook = opts.on_panic.map(|on_panic| {
+        quote! {
+            ::vexide::panic::set_hook(#on_panic as fn(&::core::panic::PanicInfo<'_>));
+        }
+    });
+
     quote! {
         #[no_mangle]
         unsafe extern "C" fn _start() -> ! {
             ::vexide::startup::startup::<#banner_enabled>(#banner_theme);
+            #panic_hook
 
             #inner
             let termination: #ret_type = ::vexide::async_runtime::block_on(
@@ -114,6 +123,7 @@ fn make_entrypoint(inner: &ItemFn, opts: MacroOpts) -> proc_macro2::TokenStream
 ///
 /// - `banner`: Allows for disabling or using a custom banner theme. When `enabled = false` the banner will be disabled. `theme` can be set to a custom `BannerTheme` struct.
 /// - `code_sig`: Allows using a custom `CodeSignature` struct to configure program behavior.
+/// - `on_panic`: Installs a function of type `fn(&PanicInfo)` as the panic hook. The hook is set immediately after the heap is initialized, before `main` is called. Requires the `panic` feature.
 ///
 /// # Examples
 ///
@@ -173,6 +183,22 @@ fn make_entrypoint(inner: &ItemFn, opts: MacroOpts) -> proc_macro2::TokenStream
 ///    println!("Hello world!")
 /// }
 /// ```
+///
+/// A custom panic hook can be installed before `main` runs:
+///
+/// ```ignore
+/// # #![no_std]
+/// # #![no_main]
+/// # use vexide::prelude::*;
+/// fn on_panic(info: &core::panic::PanicInfo<'_>) {
+///     println!("Oh no! {info}");
+/// }
+///
+/// #[vexide::main(on_panic = on_panic)]
+/// async fn main(_p: Peripherals) {
+///    panic!("Something went wrong!");
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn main(attrs: TokenStream, item: TokenStream) -> TokenStream {
     let item = parse_macro_input!(item as ItemFn);
@@ -243,6 +269,7 @@ mod test {
                 banner_enabled: false,
                 banner_theme: None,
                 code_sig: None,
+                on_panic: None,
             },
         );
         assert!(entrypoint.to_string().contains("false"));
@@ -254,6 +281,7 @@ mod test {
                 banner_enabled: true,
                 banner_theme: None,
                 code_sig: None,
+                on_panic: None,
             },
         );
         assert!(entrypoint.to_string().contains("true"));
@@ -269,6 +297,7 @@ mod test {
                 "__custom_code_sig_ident__",
                 proc_macro2::Span::call_site(),
             )),
+            on_panic: None,
         });
 
         println!("{}", code_sig.to_string());
@@ -277,6 +306,33 @@ mod test {
         ));
     }
 
+    #[test]
+    fn sets_panic_hook_from_parsed_opts() {
+        let source = quote! {
+            async fn main(_peripherals: Peripherals) {
+                println!("Hello, world!");
+            }
+        };
+        let input = syn::parse2::<ItemFn>(source).unwrap();
+
+        let entrypoint = make_entrypoint(&input, MacroOpts::default());
+        assert!(!entrypoint.to_string().contains("set_hook"));
+
+        let entrypoint = make_entrypoint(
+            &input,
+            MacroOpts {
+                on_panic: Some(Ident::new(
+                    "__custom_panic_hook_ident__",
+                    proc_macro2::Span::call_site(),
+                )),
+                ..MacroOpts::default()
+            },
+        );
+        assert!(entrypoint.to_string().contains(
+            ":: vexide :: panic :: set_hook (__custom_panic_hook_ident__ as fn (& :: core :: panic :: PanicInfo < '_ >)) ;"
+        ));
+    }
+
     #[test]
     fn requires_async() {
         let source = quote! {
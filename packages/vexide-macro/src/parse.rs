@@ -16,6 +16,8 @@ mod kw {
     custom_keyword!(theme);
 
     custom_keyword!(code_sig);
+
+    custom_keyword!(on_panic);
 }
 
 #[derive(Clone)]
@@ -23,6 +25,7 @@ pub struct MacroOpts {
     pub banner_enabled: bool,
     pub banner_theme: Option<Ident>,
     pub code_sig: Option<Ident>,
+    pub on_panic: Option<Ident>,
 }
 
 impl Default for MacroOpts {
@@ -31,6 +34,7 @@ impl Default for MacroOpts {
             banner_enabled: true,
             banner_theme: None,
             code_sig: None,
+            on_panic: None,
         }
     }
 }
@@ -53,6 +57,7 @@ impl From<Attrs> for MacroOpts {
                     }
                 }
                 Attribute::CodeSig(code_sig) => opts.code_sig = Some(code_sig.into_ident()),
+                Attribute::OnPanic(on_panic) => opts.on_panic = Some(on_panic.into_ident()),
             }
         }
         opts
@@ -74,6 +79,7 @@ impl Parse for Attrs {
 pub enum Attribute {
     Banner(Banner),
     CodeSig(CodeSig),
+    OnPanic(OnPanic),
 }
 
 impl Parse for Attribute {
@@ -83,6 +89,8 @@ impl Parse for Attribute {
             input.parse().map(Attribute::Banner)
         } else if lookahead.peek(kw::code_sig) {
             input.parse().map(Attribute::CodeSig)
+        } else if lookahead.peek(kw::on_panic) {
+            input.parse().map(Attribute::OnPanic)
         } else {
             Err(lookahead.error())
         }
@@ -230,6 +238,36 @@ impl ToTokens for CodeSig {
     }
 }
 
+pub struct OnPanic {
+    token: kw::on_panic,
+    eq: Token![=],
+    ident: Ident,
+}
+
+impl OnPanic {
+    pub fn into_ident(self) -> Ident {
+        self.ident
+    }
+}
+
+impl Parse for OnPanic {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        Ok(Self {
+            token: input.parse()?,
+            eq: input.parse()?,
+            ident: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for OnPanic {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.token.to_tokens(tokens);
+        self.eq.to_tokens(tokens);
+        self.ident.to_tokens(tokens);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use quote::quote;
@@ -267,6 +305,16 @@ mod test {
         assert_eq!(input.into_ident(), ident);
     }
 
+    #[test]
+    fn parses_on_panic_attribute() {
+        let ident = Ident::new("my_panic_hook", proc_macro2::Span::call_site());
+        let source = quote! {
+            on_panic = #ident
+        };
+        let input = syn::parse2::<OnPanic>(source).unwrap();
+        assert_eq!(input.into_ident(), ident);
+    }
+
     #[test]
     fn parses_attrs_into_macro_opts() {
         let source = quote! {
@@ -311,5 +359,14 @@ mod test {
         let opts = macro_opts_from(source);
         assert!(!opts.banner_enabled);
         assert_eq!(opts.code_sig.unwrap().to_string(), "my_code_sig");
+        assert_eq!(opts.on_panic, None);
+
+        let source = quote! {
+            on_panic = my_panic_hook
+        };
+        let opts = macro_opts_from(source);
+        assert!(opts.banner_enabled);
+        assert_eq!(opts.code_sig, None);
+        assert_eq!(opts.on_panic.unwrap().to_string(), "my_panic_hook");
     }
 }
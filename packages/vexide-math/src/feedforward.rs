@@ -0,0 +1,93 @@
+//! Feedforward Control
+//!
+//! Feedforward controllers predict the output required to reach a target from a model of the
+//! system being controlled, rather than reacting to error like a feedback controller. They are
+//! typically combined with a feedback controller such as a [`PidController`], which corrects for
+//! whatever error the model doesn't account for.
+//!
+//! [`PidController`]: crate::pid::PidController
+
+/// A feedforward controller for a DC motor.
+///
+/// The output of this controller is computed from a target velocity and acceleration using
+/// three gains:
+///
+/// ```text
+/// output = ks * sign(velocity) + kv * velocity + ka * acceleration
+/// ```
+///
+/// - `ks` is the output needed to overcome static friction and start the motor moving.
+/// - `kv` is the output needed per unit of velocity to hold a constant speed.
+/// - `ka` is the output needed per unit of acceleration.
+///
+/// The gains are in units of output (such as volts) per the units of velocity and acceleration
+/// that the controller is given, and are usually found by measuring the mechanism.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::prelude::*;
+/// use vexide::math::feedforward::MotorFeedforwardController;
+///
+/// #[vexide::main]
+/// async fn main(peripherals: Peripherals) {
+///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+///
+///     // Gains for a flywheel, in volts per RPM and volts per RPM/s.
+///     let feedforward = MotorFeedforwardController::new(0.5, 0.055, 0.002);
+///
+///     // Hold the flywheel at 150 RPM.
+///     _ = motor.set_voltage(feedforward.calculate(150.0, 0.0));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotorFeedforwardController {
+    ks: f64,
+    kv: f64,
+    ka: f64,
+}
+
+impl MotorFeedforwardController {
+    /// Creates a new feedforward controller with the given static, velocity, and acceleration
+    /// gains.
+    #[must_use]
+    pub const fn new(ks: f64, kv: f64, ka: f64) -> Self {
+        Self { ks, kv, ka }
+    }
+
+    /// Returns the static gain of the controller.
+    #[must_use]
+    pub const fn ks(&self) -> f64 {
+        self.ks
+    }
+
+    /// Returns the velocity gain of the controller.
+    #[must_use]
+    pub const fn kv(&self) -> f64 {
+        self.kv
+    }
+
+    /// Returns the acceleration gain of the controller.
+    #[must_use]
+    pub const fn ka(&self) -> f64 {
+        self.ka
+    }
+
+    /// Computes the output needed to reach the given velocity and acceleration.
+    ///
+    /// The static gain is only applied in the direction of `velocity`, and is not applied at
+    /// all if `velocity` is zero.
+    #[must_use]
+    pub const fn calculate(&self, velocity: f64, acceleration: f64) -> f64 {
+        // `f64::signum` returns 1.0 for zero, which would apply `ks` to a stationary target.
+        let direction = if velocity > 0.0 {
+            1.0
+        } else if velocity < 0.0 {
+            -1.0
+        } else {
+            0.0
+        };
+
+        self.ks * direction + self.kv * velocity + self.ka * acceleration
+    }
+}
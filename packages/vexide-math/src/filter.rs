@@ -0,0 +1,278 @@
+//! Signal Filtering
+//!
+//! Sensor readings are often noisy, which can cause problems when they are used directly
+//! as the input to a controller. This module provides simple filters that can be fed one
+//! reading at a time to produce a smoothed estimate of the underlying signal.
+//!
+//! - [`ExponentialMovingAverage`] is a cheap low-pass filter with a single tuning value.
+//! - [`KalmanFilter1D`] tracks a single value along with an estimate of its uncertainty,
+//!   weighting each new measurement by how noisy it is expected to be.
+
+/// An exponential moving average (EMA) filter.
+///
+/// Each new sample is blended with the previous output according to a smoothing factor
+/// `alpha` in the range [0.0, 1.0]:
+///
+/// ```text
+/// output = alpha * sample + (1 - alpha) * previous_output
+/// ```
+///
+/// Larger values of `alpha` respond to changes more quickly, while smaller values smooth
+/// out more noise at the cost of added lag. An `alpha` of `1.0` disables filtering entirely.
+///
+/// The first sample passed to [`ExponentialMovingAverage::update`] is used as-is to
+/// initialize the filter, so the output does not have to "ramp up" from zero.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::prelude::*;
+/// use vexide::math::filter::ExponentialMovingAverage;
+///
+/// #[vexide::main]
+/// async fn main(peripherals: Peripherals) {
+///     let sensor = DistanceSensor::new(peripherals.port_1);
+///     let mut filter = ExponentialMovingAverage::new(0.2);
+///
+///     loop {
+///         if let Ok(Some(object)) = sensor.object() {
+///             let distance = filter.update(f64::from(object.distance));
+///             println!("Smoothed distance: {distance}mm");
+///         }
+///
+///         sleep(DistanceSensor::UPDATE_INTERVAL).await;
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExponentialMovingAverage {
+    alpha: f64,
+    value: Option<f64>,
+}
+
+impl ExponentialMovingAverage {
+    /// Creates a new filter with the given smoothing factor.
+    ///
+    /// `alpha` is clamped to the range [0.0, 1.0].
+    #[must_use]
+    pub const fn new(alpha: f64) -> Self {
+        Self {
+            alpha: clamp_alpha(alpha),
+            value: None,
+        }
+    }
+
+    /// Returns the smoothing factor of the filter.
+    #[must_use]
+    pub const fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Sets the smoothing factor of the filter.
+    ///
+    /// `alpha` is clamped to the range [0.0, 1.0].
+    pub const fn set_alpha(&mut self, alpha: f64) {
+        self.alpha = clamp_alpha(alpha);
+    }
+
+    /// Returns the current output of the filter, or `None` if no samples have been provided.
+    #[must_use]
+    pub const fn value(&self) -> Option<f64> {
+        self.value
+    }
+
+    /// Clears the filter's state, causing the next sample to be used as-is.
+    pub const fn reset(&mut self) {
+        self.value = None;
+    }
+
+    /// Feeds a new sample into the filter, returning the filtered output.
+    pub const fn update(&mut self, sample: f64) -> f64 {
+        let value = match self.value {
+            Some(previous) => self.alpha * sample + (1.0 - self.alpha) * previous,
+            None => sample,
+        };
+
+        self.value = Some(value);
+        value
+    }
+}
+
+// `f64::clamp` is not usable in const contexts on our toolchain.
+const fn clamp_alpha(alpha: f64) -> f64 {
+    if alpha < 0.0 {
+        0.0
+    } else if alpha > 1.0 {
+        1.0
+    } else {
+        alpha
+    }
+}
+
+/// A one-dimensional Kalman filter.
+///
+/// This filter tracks an estimate of a single value along with the variance (uncertainty)
+/// of that estimate. Using it involves two steps, which are typically performed once per
+/// loop iteration:
+///
+/// 1. [`KalmanFilter1D::predict`] accounts for the value possibly changing since the last
+///    update by increasing the uncertainty of the estimate by `process_noise`.
+/// 2. [`KalmanFilter1D::update`] blends a new measurement into the estimate. Measurements
+///    with a lower `measurement_noise` relative to the current uncertainty have more effect.
+///
+/// Both noise parameters are variances, so they are in the squared units of the measurement.
+/// Unlike an [`ExponentialMovingAverage`], the amount of smoothing adapts automatically as
+/// the filter becomes more or less certain of its estimate.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::prelude::*;
+/// use vexide::math::filter::KalmanFilter1D;
+///
+/// #[vexide::main]
+/// async fn main(peripherals: Peripherals) {
+///     let sensor = DistanceSensor::new(peripherals.port_1);
+///     let mut filter = KalmanFilter1D::new(0.0, 1000.0);
+///
+///     loop {
+///         filter.predict(4.0);
+///
+///         if let Ok(Some(object)) = sensor.object() {
+///             let distance = filter.update(f64::from(object.distance), 100.0);
+///             println!("Estimated distance: {distance}mm");
+///         }
+///
+///         sleep(DistanceSensor::UPDATE_INTERVAL).await;
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KalmanFilter1D {
+    estimate: f64,
+    variance: f64,
+}
+
+impl KalmanFilter1D {
+    /// Creates a new filter with an initial estimate and the variance of that estimate.
+    ///
+    /// If the initial value is not known, use a large `variance` so that the first
+    /// measurement is trusted almost entirely.
+    #[must_use]
+    pub const fn new(estimate: f64, variance: f64) -> Self {
+        Self { estimate, variance }
+    }
+
+    /// Returns the current estimate of the value.
+    #[must_use]
+    pub const fn estimate(&self) -> f64 {
+        self.estimate
+    }
+
+    /// Returns the variance of the current estimate.
+    #[must_use]
+    pub const fn variance(&self) -> f64 {
+        self.variance
+    }
+
+    /// Increases the uncertainty of the estimate to account for the value changing over time.
+    ///
+    /// `process_noise` is the expected variance in how much the value changes between updates.
+    pub const fn predict(&mut self, process_noise: f64) {
+        self.variance += process_noise;
+    }
+
+    /// Blends a new measurement into the estimate, returning the updated estimate.
+    ///
+    /// `measurement_noise` is the variance of the sensor's readings.
+    pub const fn update(&mut self, measurement: f64, measurement_noise: f64) -> f64 {
+        let total_variance = self.variance + measurement_noise;
+
+        // If neither the estimate nor the measurement has any uncertainty, there is
+        // nothing to weight by, so keep the current estimate.
+        if total_variance > 0.0 {
+            let gain = self.variance / total_variance;
+
+            self.estimate += gain * (measurement - self.estimate);
+            self.variance *= 1.0 - gain;
+        }
+
+        self.estimate
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)] // outputs compared exactly are copied from the input samples
+mod tests {
+    use vexide_core::float::Float;
+
+    use super::{ExponentialMovingAverage, KalmanFilter1D};
+
+    const TOLERANCE: f64 = 1e-9;
+
+    #[test]
+    fn ema_seeds_from_first_sample() {
+        let mut filter = ExponentialMovingAverage::new(0.25);
+        assert_eq!(filter.value(), None);
+        assert_eq!(filter.update(8.0), 8.0);
+        assert_eq!(filter.update(0.0), 6.0);
+
+        filter.reset();
+        assert_eq!(filter.update(4.0), 4.0);
+    }
+
+    #[test]
+    fn ema_converges() {
+        let mut filter = ExponentialMovingAverage::new(0.5);
+        filter.update(0.0);
+
+        for _ in 0..64 {
+            filter.update(10.0);
+        }
+        assert!((filter.value().unwrap() - 10.0).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn ema_alpha_bounds() {
+        // An alpha of 0 holds the first sample forever.
+        let mut filter = ExponentialMovingAverage::new(0.0);
+        assert_eq!(filter.update(3.0), 3.0);
+        assert_eq!(filter.update(10.0), 3.0);
+        assert_eq!(filter.update(-10.0), 3.0);
+
+        // An alpha of 1 passes every sample through unfiltered.
+        let mut filter = ExponentialMovingAverage::new(1.0);
+        assert_eq!(filter.update(3.0), 3.0);
+        assert_eq!(filter.update(10.0), 10.0);
+        assert_eq!(filter.update(-10.0), -10.0);
+
+        // Values outside of the valid range are clamped.
+        assert_eq!(ExponentialMovingAverage::new(-1.0).alpha(), 0.0);
+        assert_eq!(ExponentialMovingAverage::new(2.0).alpha(), 1.0);
+    }
+
+    #[test]
+    fn kalman_seeds_from_first_measurement() {
+        // With a very uncertain initial estimate, the first measurement is trusted almost entirely.
+        let mut filter = KalmanFilter1D::new(0.0, 1e12);
+        assert!((filter.update(50.0, 1.0) - 50.0).abs() < 1e-6);
+        assert!(filter.variance() < 1.0);
+    }
+
+    #[test]
+    fn kalman_converges() {
+        let mut filter = KalmanFilter1D::new(0.0, 100.0);
+
+        let mut variance = f64::INFINITY;
+        for _ in 0..500 {
+            filter.predict(0.01);
+            filter.update(20.0, 4.0);
+
+            // The filter only becomes more certain while the measurements agree.
+            assert!(filter.variance() <= variance);
+            variance = filter.variance();
+        }
+
+        assert!((filter.estimate() - 20.0).abs() < TOLERANCE);
+    }
+}
@@ -0,0 +1,16 @@
+//! Math utilities for robotics with [`vexide`](https://crates.io/crates/vexide).
+//!
+//! This crate provides common building blocks for controlling and tracking a robot
+//! that are independent of any specific device.
+//!
+//! Included in this crate:
+//! - Position tracking from wheel encoders: [`odometry`]
+//! - Smoothing of noisy sensor readings: [`filter`]
+//! - Feedback and feedforward control: [`pid`], [`feedforward`]
+
+#![no_std]
+
+pub mod feedforward;
+pub mod filter;
+pub mod odometry;
+pub mod pid;
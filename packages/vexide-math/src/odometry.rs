@@ -0,0 +1,214 @@
+//! Wheel Odometry
+//!
+//! Odometry is the process of estimating a robot's position over time by integrating
+//! small changes in the distance traveled by its wheels. This module provides odometry
+//! models that can be fed readings from devices such as [`Motor`]s or [`RotationSensor`]s.
+//!
+//! # Coordinate System
+//!
+//! Positions are tracked in the units that the tracker was configured with (the same units
+//! as the provided track width and wheel radius). Headings are in radians, with a heading
+//! of zero facing along the positive x-axis and positive headings rotating counterclockwise
+//! towards the positive y-axis.
+//!
+//! [`Motor`]: https://docs.rs/vexide-devices/latest/vexide_devices/smart/motor/struct.Motor.html
+//! [`RotationSensor`]: https://docs.rs/vexide-devices/latest/vexide_devices/smart/rotation/struct.RotationSensor.html
+
+use vexide_core::float::Float;
+
+/// Position tracking for a differential (tank) drivetrain.
+///
+/// This tracker estimates the pose of a robot using the rotation of a wheel on each side of
+/// the drivetrain. Each update assumes that the robot traveled along a circular arc between
+/// readings, which is much more accurate than a straight-line approximation when turning.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::prelude::*;
+/// use vexide::math::odometry::DifferentialOdometry;
+///
+/// #[vexide::main]
+/// async fn main(peripherals: Peripherals) {
+///     let left = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+///     let right = Motor::new(peripherals.port_2, Gearset::Green, Direction::Reverse);
+///
+///     // 12.5" track width, 2" wheel radius. Positions will be tracked in inches.
+///     let mut odometry = DifferentialOdometry::new(12.5, 2.0);
+///
+///     let mut prev_left = left.position().unwrap().as_radians();
+///     let mut prev_right = right.position().unwrap().as_radians();
+///
+///     loop {
+///         let left_angle = left.position().unwrap().as_radians();
+///         let right_angle = right.position().unwrap().as_radians();
+///
+///         let (x, y, heading) = odometry.update(left_angle - prev_left, right_angle - prev_right);
+///         println!("x: {x}, y: {y}, heading: {heading}");
+///
+///         prev_left = left_angle;
+///         prev_right = right_angle;
+///
+///         sleep(Motor::UPDATE_INTERVAL).await;
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifferentialOdometry {
+    track_width: f64,
+    wheel_radius: f64,
+    x: f64,
+    y: f64,
+    heading: f64,
+}
+
+impl DifferentialOdometry {
+    /// Creates a new odometry tracker starting at the origin with a heading of zero.
+    ///
+    /// - `track_width` is the distance between the left and right tracking wheels.
+    /// - `wheel_radius` is the radius of the tracking wheels.
+    ///
+    /// Both values should be in the same units, which will also be used for tracked positions.
+    #[must_use]
+    pub const fn new(track_width: f64, wheel_radius: f64) -> Self {
+        Self {
+            track_width,
+            wheel_radius,
+            x: 0.0,
+            y: 0.0,
+            heading: 0.0,
+        }
+    }
+
+    /// Returns the distance between the left and right tracking wheels.
+    #[must_use]
+    pub const fn track_width(&self) -> f64 {
+        self.track_width
+    }
+
+    /// Returns the radius of the tracking wheels.
+    #[must_use]
+    pub const fn wheel_radius(&self) -> f64 {
+        self.wheel_radius
+    }
+
+    /// Returns the current estimated `(x, y, heading)` of the robot.
+    #[must_use]
+    pub const fn pose(&self) -> (f64, f64, f64) {
+        (self.x, self.y, self.heading)
+    }
+
+    /// Overrides the current estimated pose of the robot.
+    ///
+    /// This is useful for setting a known starting position at the beginning of a match.
+    pub const fn set_pose(&mut self, x: f64, y: f64, heading: f64) {
+        self.x = x;
+        self.y = y;
+        self.heading = heading;
+    }
+
+    /// Updates the tracked pose from the change in rotation of each wheel since the last update,
+    /// returning the new `(x, y, heading)` of the robot.
+    ///
+    /// `left_delta` and `right_delta` are the angles (in radians) that the left and right
+    /// wheels have rotated since the previous call. Forward wheel rotation should be positive
+    /// on both sides.
+    ///
+    /// # Algorithm
+    ///
+    /// The distance traveled by each wheel is `delta * wheel_radius`. From these:
+    ///
+    /// - The change in heading is `(right - left) / track_width`.
+    /// - The distance traveled by the center of the robot is `(left + right) / 2`.
+    ///
+    /// The robot is assumed to have moved along a circular arc, so the straight-line chord
+    /// of that arc (`2 * (distance / Δheading) * sin(Δheading / 2)`) is applied in the
+    /// direction of the average heading over the update. When the heading does not change,
+    /// this reduces to moving straight ahead by `distance`.
+    pub fn update(&mut self, left_delta: f64, right_delta: f64) -> (f64, f64, f64) {
+        let left_distance = left_delta * self.wheel_radius;
+        let right_distance = right_delta * self.wheel_radius;
+
+        let delta_heading = (right_distance - left_distance) / self.track_width;
+        let distance = (left_distance + right_distance) / 2.0;
+
+        let chord = if delta_heading.abs() < f64::EPSILON {
+            distance
+        } else {
+            2.0 * (distance / delta_heading) * (delta_heading / 2.0).sin()
+        };
+
+        let average_heading = self.heading + delta_heading / 2.0;
+
+        self.x += chord * average_heading.cos();
+        self.y += chord * average_heading.sin();
+        self.heading += delta_heading;
+
+        self.pose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use vexide_core::float::Float;
+
+    use super::DifferentialOdometry;
+
+    fn assert_pose_eq(actual: (f64, f64, f64), expected: (f64, f64, f64)) {
+        const TOLERANCE: f64 = 1e-9;
+
+        assert!(
+            (actual.0 - expected.0).abs() < TOLERANCE
+                && (actual.1 - expected.1).abs() < TOLERANCE
+                && (actual.2 - expected.2).abs() < TOLERANCE,
+            "expected pose {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn drive_straight() {
+        let mut odometry = DifferentialOdometry::new(10.0, 2.0);
+
+        assert_pose_eq(odometry.update(1.0, 1.0), (2.0, 0.0, 0.0));
+        assert_pose_eq(odometry.update(0.5, 0.5), (3.0, 0.0, 0.0));
+
+        // Driving straight follows the current heading.
+        odometry.set_pose(0.0, 0.0, FRAC_PI_2);
+        assert_pose_eq(odometry.update(1.0, 1.0), (0.0, 2.0, FRAC_PI_2));
+    }
+
+    #[test]
+    fn constant_radius_arc() {
+        // A quarter circle to the left with a radius of 10 around the center of the robot,
+        // so the left wheel follows a radius of 5 and the right wheel a radius of 15.
+        let mut odometry = DifferentialOdometry::new(10.0, 1.0);
+        assert_pose_eq(
+            odometry.update(5.0 * FRAC_PI_2, 15.0 * FRAC_PI_2),
+            (10.0, 10.0, FRAC_PI_2),
+        );
+
+        // Splitting the same arc across several updates ends at the same pose.
+        let mut odometry = DifferentialOdometry::new(10.0, 1.0);
+        odometry.update(5.0 * FRAC_PI_4, 15.0 * FRAC_PI_4);
+        assert_pose_eq(
+            odometry.update(5.0 * FRAC_PI_4, 15.0 * FRAC_PI_4),
+            (10.0, 10.0, FRAC_PI_2),
+        );
+    }
+
+    #[test]
+    fn turn_in_place() {
+        let mut odometry = DifferentialOdometry::new(2.0, 1.0);
+
+        assert_pose_eq(
+            odometry.update(-FRAC_PI_2, FRAC_PI_2),
+            (0.0, 0.0, FRAC_PI_2),
+        );
+        assert_pose_eq(
+            odometry.update(FRAC_PI_2 * 3.0, -FRAC_PI_2 * 3.0),
+            (0.0, 0.0, -PI),
+        );
+    }
+}
@@ -0,0 +1,248 @@
+//! PID Control
+//!
+//! A PID controller is a feedback controller that computes an output from the error between
+//! a desired setpoint and a measured value. The output is the sum of three terms:
+//!
+//! - The proportional term, which reacts to the current error.
+//! - The integral term, which reacts to error that has accumulated over time.
+//! - The derivative term, which reacts to how quickly the error is changing.
+//!
+//! PID controllers can optionally be combined with a [`MotorFeedforwardController`], which
+//! provides most of the output from a model of the mechanism and leaves the feedback terms to
+//! correct for the remaining error.
+
+use core::time::Duration;
+
+use crate::feedforward::MotorFeedforwardController;
+
+/// A proportional-integral-derivative (PID) controller.
+///
+/// # Feedforward
+///
+/// A [`MotorFeedforwardController`] may be attached to the controller with
+/// [`PidController::with_feedforward`]. When attached, the setpoint is treated as a target
+/// velocity, and the feedforward output for that velocity is added to the PID output on each
+/// update. The target acceleration passed to the feedforward controller is computed from how
+/// much the setpoint changed since the last update.
+///
+/// # Output Limits
+///
+/// The output of the controller can be limited with [`PidController::output_clamp`]. While the
+/// output is saturated at one of its limits, the integral term stops accumulating error in the
+/// direction of that limit, which prevents it from "winding up" and overshooting once the
+/// error is corrected.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::prelude::*;
+/// use vexide::math::{feedforward::MotorFeedforwardController, pid::PidController};
+///
+/// #[vexide::main]
+/// async fn main(peripherals: Peripherals) {
+///     let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+///
+///     // Control the motor's velocity in RPM, with an output in volts.
+///     let mut controller = PidController::new(0.01, 0.002, 0.0)
+///         .with_feedforward(MotorFeedforwardController::new(0.5, 0.055, 0.002))
+///         .output_clamp(-Motor::V5_MAX_VOLTAGE, Motor::V5_MAX_VOLTAGE);
+///
+///     loop {
+///         let velocity = motor.velocity().unwrap();
+///         let voltage = controller.update(150.0, velocity, Motor::UPDATE_INTERVAL);
+///         _ = motor.set_voltage(voltage);
+///
+///         sleep(Motor::UPDATE_INTERVAL).await;
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PidController {
+    kp: f64,
+    ki: f64,
+    kd: f64,
+    integral: f64,
+    prev_error: Option<f64>,
+    prev_setpoint: Option<f64>,
+    feedforward: Option<MotorFeedforwardController>,
+    output_limits: Option<(f64, f64)>,
+}
+
+impl PidController {
+    /// Creates a new PID controller with the given proportional, integral, and derivative gains.
+    #[must_use]
+    pub const fn new(kp: f64, ki: f64, kd: f64) -> Self {
+        Self {
+            kp,
+            ki,
+            kd,
+            integral: 0.0,
+            prev_error: None,
+            prev_setpoint: None,
+            feedforward: None,
+            output_limits: None,
+        }
+    }
+
+    /// Adds a feedforward term to the controller's output.
+    ///
+    /// See the [type-level documentation](PidController#feedforward) for details on how the
+    /// feedforward output is computed.
+    #[must_use]
+    pub const fn with_feedforward(mut self, feedforward: MotorFeedforwardController) -> Self {
+        self.feedforward = Some(feedforward);
+        self
+    }
+
+    /// Limits the controller's output to the range `[min, max]`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `min` is greater than `max`.
+    #[must_use]
+    pub const fn output_clamp(mut self, min: f64, max: f64) -> Self {
+        assert!(min <= max, "min must be less than or equal to max");
+        self.output_limits = Some((min, max));
+        self
+    }
+
+    /// Returns the proportional, integral, and derivative gains of the controller.
+    #[must_use]
+    pub const fn gains(&self) -> (f64, f64, f64) {
+        (self.kp, self.ki, self.kd)
+    }
+
+    /// Sets the proportional, integral, and derivative gains of the controller.
+    pub const fn set_gains(&mut self, kp: f64, ki: f64, kd: f64) {
+        self.kp = kp;
+        self.ki = ki;
+        self.kd = kd;
+    }
+
+    /// Returns the feedforward controller attached to this controller, if any.
+    #[must_use]
+    pub const fn feedforward(&self) -> Option<MotorFeedforwardController> {
+        self.feedforward
+    }
+
+    /// Returns the output limits of the controller, if any.
+    #[must_use]
+    pub const fn output_limits(&self) -> Option<(f64, f64)> {
+        self.output_limits
+    }
+
+    /// Clears the controller's accumulated integral and previous error.
+    ///
+    /// This should be called when the controller is reused for a new movement, so that state
+    /// from the previous movement does not affect the output.
+    pub const fn reset(&mut self) {
+        self.integral = 0.0;
+        self.prev_error = None;
+        self.prev_setpoint = None;
+    }
+
+    /// Computes the controller's output for a new measurement.
+    ///
+    /// `dt` is the time elapsed since the previous update. The derivative term and the
+    /// feedforward acceleration are zero on the first update after the controller is created
+    /// or [reset](PidController::reset), since there is no previous update to compare against.
+    pub const fn update(&mut self, setpoint: f64, measurement: f64, dt: Duration) -> f64 {
+        let dt = dt.as_secs_f64();
+        let error = setpoint - measurement;
+
+        let derivative = match self.prev_error {
+            Some(prev_error) if dt > 0.0 => (error - prev_error) / dt,
+            _ => 0.0,
+        };
+
+        let prev_integral = self.integral;
+        self.integral += error * dt;
+
+        let mut output = self.kp * error + self.ki * self.integral + self.kd * derivative;
+
+        if let Some(feedforward) = self.feedforward {
+            let acceleration = match self.prev_setpoint {
+                Some(prev_setpoint) if dt > 0.0 => (setpoint - prev_setpoint) / dt,
+                _ => 0.0,
+            };
+
+            output += feedforward.calculate(setpoint, acceleration);
+        }
+
+        if let Some((min, max)) = self.output_limits {
+            // Stop integrating while saturated so the integral doesn't wind up past the limit.
+            if output > max {
+                if error > 0.0 {
+                    self.integral = prev_integral;
+                }
+                output = max;
+            } else if output < min {
+                if error < 0.0 {
+                    self.integral = prev_integral;
+                }
+                output = min;
+            }
+        }
+
+        self.prev_error = Some(error);
+        self.prev_setpoint = Some(setpoint);
+
+        output
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)] // every expected output is exactly representable
+mod tests {
+    use core::time::Duration;
+
+    use super::PidController;
+    use crate::feedforward::MotorFeedforwardController;
+
+    const DT: Duration = Duration::from_millis(500);
+
+    #[test]
+    fn output_clamp() {
+        let mut controller = PidController::new(1.0, 0.0, 0.0).output_clamp(-2.0, 3.0);
+
+        assert_eq!(controller.update(10.0, 0.0, DT), 3.0);
+        assert_eq!(controller.update(-10.0, 0.0, DT), -2.0);
+        assert_eq!(controller.update(1.0, 0.0, DT), 1.0);
+    }
+
+    #[test]
+    fn integral_anti_windup() {
+        let mut controller = PidController::new(1.0, 1.0, 0.0).output_clamp(-1.0, 1.0);
+
+        // While saturated, the error shouldn't accumulate in the integral.
+        for _ in 0..10 {
+            assert_eq!(controller.update(10.0, 0.0, DT), 1.0);
+        }
+
+        // Once the setpoint is reached, there is no wound-up integral holding the output high.
+        assert_eq!(controller.update(0.0, 0.0, DT), 0.0);
+
+        // Error that pulls the output back out of saturation is still integrated.
+        let mut controller = PidController::new(0.0, 1.0, 0.0).output_clamp(-1.0, 1.0);
+        assert_eq!(controller.update(1.0, 0.0, DT), 0.5);
+        assert_eq!(controller.update(1.0, 0.0, DT), 1.0);
+        assert_eq!(controller.update(1.0, 0.0, DT), 1.0);
+        assert_eq!(controller.update(-1.0, 0.0, DT), 0.5);
+    }
+
+    #[test]
+    fn feedforward_acceleration() {
+        let mut controller = PidController::new(0.0, 0.0, 0.0)
+            .with_feedforward(MotorFeedforwardController::new(0.0, 1.0, 2.0));
+
+        // There is no previous setpoint on the first update, so only the velocity term applies.
+        assert_eq!(controller.update(1.0, 0.0, DT), 1.0);
+
+        // The setpoint changed by 2.0 over 0.5 seconds, so the target acceleration is 4.0.
+        assert_eq!(controller.update(3.0, 0.0, DT), 3.0 + 2.0 * 4.0);
+
+        // Resetting clears the previous setpoint along with the rest of the state.
+        controller.reset();
+        assert_eq!(controller.update(3.0, 0.0, DT), 3.0);
+    }
+}
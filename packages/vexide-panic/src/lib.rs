@@ -117,7 +117,8 @@ fn draw_error(display: &mut Display, msg: &str, backtrace: &Backtrace) {
 /// on the V5 Brain display.
 ///
 /// Note that if `display_panics` is not enabled, this function will not return.
-/// It will immediately exit the program after printing the panic message. If
+/// It will immediately exit the program with code `1` (see
+/// [`exit_with_code`](vexide_core::program::exit_with_code)) after printing the panic message. If
 /// you do not want this behavior, you should use your own
 ///
 /// # Examples
@@ -151,7 +152,7 @@ pub fn default_panic_hook(info: &core::panic::PanicInfo<'_>) {
     }
 
     #[cfg(not(feature = "display_panics"))]
-    vexide_core::program::exit();
+    vexide_core::program::exit_with_code(1);
 }
 
 /// The panic hook type
@@ -249,7 +250,7 @@ pub fn panic(info: &core::panic::PanicInfo<'_>) -> ! {
     // overflow. In this instance, something is likely very wrong, so it's better
     // to just abort rather than recursively panicking.
     if !FIRST_PANIC.swap(false, Ordering::Relaxed) {
-        vexide_core::program::exit();
+        vexide_core::program::exit_with_code(1);
     }
 
     // Try to lock the HOOK mutex. If we can't, we'll just use the default panic
@@ -0,0 +1,48 @@
+//! Heap configuration.
+//!
+//! By default, [`startup`](crate::startup) claims the memory between the `__heap_start` and
+//! `__heap_end` symbols defined in the linkerscript as heap space. Custom runtimes that need
+//! to place the heap elsewhere (for example, to exclude the memory used by a linked file) can
+//! override these bounds at runtime using [`set_heap_region`].
+
+/// Re-initializes the heap allocator to use the memory between `start` and `end`.
+///
+/// This replaces the heap region claimed by [`startup`](crate::startup), and should be
+/// called immediately afterwards, before anything is allocated on the heap.
+///
+/// # Safety
+///
+/// - This must be called before any heap allocations are made. Memory allocated before this
+///   function is called must never be used or deallocated.
+/// - The memory between `start` and `end` must be valid for reads and writes, and must not be
+///   used for anything else (such as the stack, or a linked file) while the allocator is in use.
+///
+/// # Panics
+///
+/// Panics if `start` or `end` are null, or if the region is too small to be used as heap space.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide_startup::{allocator::set_heap_region, banner::themes::THEME_DEFAULT};
+///
+/// static mut HEAP: [u8; 0x1000] = [0; 0x1000];
+///
+/// #[no_mangle]
+/// unsafe extern "C" fn _start() -> ! {
+///     unsafe {
+///         vexide_startup::startup::<false>(THEME_DEFAULT);
+///
+///         let heap = &raw mut HEAP;
+///         set_heap_region(heap.cast(), heap.cast::<u8>().add(0x1000));
+///     }
+///
+///     loop {}
+/// }
+/// ```
+pub unsafe fn set_heap_region(start: *mut u8, end: *mut u8) {
+    // SAFETY: Caller guarantees that no allocations have been made and that the region is valid.
+    unsafe {
+        vexide_core::allocator::reclaim(start, end);
+    }
+}
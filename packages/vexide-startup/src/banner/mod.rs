@@ -2,49 +2,237 @@
 //!
 //! The banner is automatically printed by the code generated from the `vexide::main` macro,
 //! but it can be printed manually with a given theme using the [`print`] function.
+//! If the `display` feature is enabled, the banner can also be drawn to the Brain's
+//! screen using `print_to_display`.
 //! In vexide, you can change the theme of the banner by using the banner attribute in the `vexide::main` macro.
 //!
 //! For a full list of premade themes and more theme documentation, see the [`themes`] module.
 
+#[cfg(feature = "display")]
+use alloc::{format, string::String};
+
 use themes::BannerTheme;
 use vex_sdk::vexBatteryCapacityGet;
-use vexide_core::{competition, os, println, time};
+use vexide_core::{competition, os, print, println, time};
+#[cfg(feature = "display")]
+use vexide_devices::{
+    display::{Display, Font, FontFamily, FontSize, Text},
+    math::Point2,
+    rgb::Rgb,
+};
 
 pub mod themes;
 
+const VEXIDE_VERSION: &str = "0.6.1";
+
 /// Prints the startup banner to stdout.
 ///
 /// This function is used internally in the [`startup`](crate::startup) function to print the banner.
 #[inline]
 pub fn print(theme: BannerTheme) {
-    const VEXIDE_VERSION: &str = "0.6.1";
-
-    println!(
-        "
-{lp1}=%%%%%#-  {ls}-#%%%%-\x1B[0m{lp1}  :*%%%%%+.\x1B{cv}   {emoji} vexide {vexide_version}\x1B[0m
-{lp2}  -#%%%%#-  {ls}:%-\x1B[0m{lp2}  -*%%%%#\x1B[0m       ---------------
-{lp3}    *%%%%#=   -#%%%%%+\x1B[0m         ╭─\x1B{mk}🔲 VEXos:\x1B[0m {vexos_version}
-{lp4}      *%%%%%+#%%%%%%%#=\x1B[0m        ├─\x1B{mk}🦀 Rust:\x1B[0m {rust_version}
-{lp5}        *%%%%%%%*-+%%%%%+\x1B[0m      ├─\x1B{mk}🏆 Mode:\x1B[0m {competition_mode:?}
-{lp6}          +%%%*:   .+###%#\x1B[0m     ├─\x1B{mk}🔋 Battery:\x1B[0m {battery}%
-{lp7}           .%:\x1B[0m                 ╰─\x1B{mk}⌚ Uptime:\x1B[0m {uptime:.2?}
-",
-        lp1 = theme.logo_primary[0],
-        lp2 = theme.logo_primary[1],
-        lp3 = theme.logo_primary[2],
-        lp4 = theme.logo_primary[3],
-        lp5 = theme.logo_primary[4],
-        lp6 = theme.logo_primary[5],
-        lp7 = theme.logo_primary[6],
-        ls = theme.logo_secondary,
-        cv = theme.crate_version,
-        mk = theme.metadata_key,
-        emoji = theme.emoji,
-        vexide_version = VEXIDE_VERSION,
-        vexos_version = os::system_version(),
-        battery = unsafe { vexBatteryCapacityGet() } as u8,
-        rust_version = compile_time::rustc_version_str!(),
-        competition_mode = competition::mode(),
-        uptime = time::uptime(),
+    const METADATA_COLUMN: usize = 31;
+
+    let key = theme.metadata_key;
+    let battery = unsafe { vexBatteryCapacityGet() } as u8;
+
+    println!();
+
+    for (line, segments) in LOGO.into_iter().enumerate() {
+        let mut width = 0;
+
+        for &(text, secondary) in segments {
+            if secondary {
+                print!("{}{text}\x1B[0m", theme.logo_secondary);
+            } else {
+                print!("{}{text}", theme.logo_primary[line]);
+            }
+
+            width += text.len();
+        }
+
+        print!("\x1B[0m{:1$}", "", METADATA_COLUMN - width);
+
+        match line {
+            0 => println!(
+                "\x1B{}{} vexide {VEXIDE_VERSION}\x1B[0m",
+                theme.crate_version, theme.emoji
+            ),
+            1 => println!("---------------"),
+            2 => println!("╭─\x1B{key}🔲 VEXos:\x1B[0m {}", os::system_version()),
+            3 => println!(
+                "├─\x1B{key}🦀 Rust:\x1B[0m {}",
+                compile_time::rustc_version_str!()
+            ),
+            4 => println!("├─\x1B{key}🏆 Mode:\x1B[0m {:?}", competition::mode()),
+            5 => println!("├─\x1B{key}🔋 Battery:\x1B[0m {battery}%"),
+            _ => println!("╰─\x1B{key}⌚ Uptime:\x1B[0m {:.2?}", time::uptime()),
+        }
+    }
+
+    println!();
+}
+
+/// Segments of each line of the vexide logo, marking which use the secondary logo color.
+const LOGO: [&[(&str, bool)]; 7] = [
+    &[
+        ("=%%%%%#-  ", false),
+        ("-#%%%%-", true),
+        ("  :*%%%%%+.", false),
+    ],
+    &[("  -#%%%%#-  ", false), (":%-", true), ("  -*%%%%#", false)],
+    &[("    *%%%%#=   -#%%%%%+", false)],
+    &[("      *%%%%%+#%%%%%%%#=", false)],
+    &[("        *%%%%%%%*-+%%%%%+", false)],
+    &[("          +%%%*:   .+###%#", false)],
+    &[("           .%:", false)],
+];
+
+/// Draws the startup banner to the Brain's display.
+///
+/// This shows the same logo and program information as [`print`], using the foreground
+/// colors of `theme`. Theme colors are parsed from their ANSI escape codes, and any escape
+/// that does not set a foreground color is drawn in white. The banner's emoji is not drawn,
+/// since the display's fonts do not support emoji.
+#[cfg(feature = "display")]
+pub fn print_to_display(display: &mut Display, theme: BannerTheme) {
+    const MARGIN: i16 = 16;
+    const METADATA_COLUMN: i16 = 28;
+
+    let font = Font::new(FontSize::SMALL, FontFamily::Monospace);
+    let char_width = font.char_width() as i16;
+    let line_height = font.line_height() as i16;
+
+    let mut draw = |text: &str, column: i16, line: i16, color: Rgb<u8>| {
+        display.draw_text(
+            &Text::new(
+                text,
+                font,
+                Point2 {
+                    x: MARGIN + column * char_width,
+                    y: MARGIN + line * line_height,
+                },
+            ),
+            color,
+            None,
+        );
+    };
+
+    for (line, segments) in (0..).zip(LOGO) {
+        let mut column = 0;
+
+        for &(text, secondary) in segments {
+            let escape = if secondary {
+                theme.logo_secondary
+            } else {
+                theme.logo_primary[line as usize]
+            };
+
+            draw(text, column, line, ansi_foreground(escape));
+            column += text.len() as i16;
+        }
+    }
+
+    let white = Rgb::new(255, 255, 255);
+    draw(
+        &format!("vexide {VEXIDE_VERSION}"),
+        METADATA_COLUMN,
+        0,
+        ansi_foreground(theme.crate_version),
     );
+
+    let metadata = [
+        ("VEXos: ", format!("{}", os::system_version())),
+        ("Rust: ", String::from(compile_time::rustc_version_str!())),
+        ("Mode: ", format!("{:?}", competition::mode())),
+        (
+            "Battery: ",
+            format!("{}%", unsafe { vexBatteryCapacityGet() } as u8),
+        ),
+        ("Uptime: ", format!("{:.2?}", time::uptime())),
+    ];
+
+    for (line, (key, value)) in (2..).zip(metadata) {
+        draw(
+            key,
+            METADATA_COLUMN,
+            line,
+            ansi_foreground(theme.metadata_key),
+        );
+        draw(&value, METADATA_COLUMN + key.len() as i16, line, white);
+    }
+}
+
+/// Returns the foreground color set by an ANSI SGR escape code, or white if it sets none.
+///
+/// Supports 24-bit (`38;2;r;g;b`), 256-color (`38;5;n`), and basic (`30`-`37`, `90`-`97`)
+/// foreground colors. The leading `ESC` byte is optional.
+#[cfg(feature = "display")]
+fn ansi_foreground(escape: &str) -> Rgb<u8> {
+    /// The standard xterm colors for the basic and bright palettes.
+    const PALETTE: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+
+    fn palette_256(index: u8) -> (u8, u8, u8) {
+        match index {
+            0..=15 => PALETTE[index as usize],
+            16..=231 => {
+                let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+                let index = index - 16;
+                (level(index / 36), level((index % 36) / 6), level(index % 6))
+            }
+            232..=255 => {
+                let gray = 8 + (index - 232) * 10;
+                (gray, gray, gray)
+            }
+        }
+    }
+
+    let mut color = (255, 255, 255);
+
+    let params = escape
+        .trim_start_matches('\x1B')
+        .trim_start_matches('[')
+        .trim_end_matches('m');
+    let mut params = params.split(';').map(|param| param.parse::<u8>().ok());
+
+    while let Some(param) = params.next() {
+        match param {
+            Some(code @ 30..=37) => color = PALETTE[(code - 30) as usize],
+            Some(code @ 90..=97) => color = PALETTE[(code - 90 + 8) as usize],
+            Some(38) => match params.next().flatten() {
+                Some(2) => {
+                    if let (Some(Some(r)), Some(Some(g)), Some(Some(b))) =
+                        (params.next(), params.next(), params.next())
+                    {
+                        color = (r, g, b);
+                    }
+                }
+                Some(5) => {
+                    if let Some(Some(index)) = params.next() {
+                        color = palette_256(index);
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    Rgb::new(color.0, color.1, color.2)
 }
@@ -8,14 +8,21 @@
 //!   startup process by clearing the `.bss` section (intended for uninitialized data)
 //!   and initializing vexide's heap allocator.
 //!
+//! - The heap bounds may then be overridden at runtime using [`allocator::set_heap_region`].
+//!
 //! This crate is NOT a crt0 implementation. No global constructors are called.
 
 #![no_std]
 #![allow(clippy::needless_doctest_main)]
 
+#[cfg(feature = "display")]
+extern crate alloc;
+
 use banner::themes::BannerTheme;
 use bitflags::bitflags;
 
+#[cfg(target_vendor = "vex")]
+pub mod allocator;
 pub mod banner;
 mod patcher;
 
@@ -52,12 +59,23 @@ bitflags! {
     #[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
     pub struct ProgramFlags: u32 {
         /// Inverts the background color to pure white.
+        ///
+        /// VEXos reads this flag when the program is loaded and clears the display to white
+        /// rather than black before user code runs. The default foreground color used by the
+        /// SDK's drawing functions is inverted to black to match.
         const INVERT_DEFAULT_GRAPHICS = 1 << 0;
 
         /// VEXos scheduler simple tasks will be killed when the program requests exit.
+        ///
+        /// Without this flag, tasks registered with the VEXos scheduler may continue running
+        /// briefly after the program exits until VEXos unloads the program.
         const KILL_TASKS_ON_EXIT = 1 << 1;
 
         /// If VEXos is using the Light theme, inverts the background color to pure white.
+        ///
+        /// This behaves like [`ProgramFlags::INVERT_DEFAULT_GRAPHICS`], but only takes effect
+        /// when the Light theme is selected in the brain's settings, allowing programs to match
+        /// the user's chosen theme.
         const THEMED_DEFAULT_GRAPHICS = 1 << 2;
     }
 }
@@ -84,6 +102,51 @@ impl CodeSignature {
             [0; 4],
         )
     }
+
+    /// Starts building a signature for a user program.
+    ///
+    /// The returned builder uses [`ProgramType::User`] and [`ProgramOwner::Partner`], which
+    /// is the signature used by almost every vexide program. Call
+    /// [`CodeSignatureBuilder::with_flags`] to finish building the signature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vexide_startup::{CodeSignature, ProgramFlags};
+    ///
+    /// static CODE_SIG: CodeSignature =
+    ///     CodeSignature::user_program().with_flags(ProgramFlags::INVERT_DEFAULT_GRAPHICS);
+    /// ```
+    #[must_use]
+    pub const fn user_program() -> CodeSignatureBuilder {
+        CodeSignatureBuilder {
+            program_type: ProgramType::User,
+            owner: ProgramOwner::Partner,
+        }
+    }
+}
+
+/// Builder for a [`CodeSignature`].
+///
+/// This is created using [`CodeSignature::user_program`].
+pub struct CodeSignatureBuilder {
+    program_type: ProgramType,
+    owner: ProgramOwner,
+}
+
+impl CodeSignatureBuilder {
+    /// Sets the owner of the program.
+    #[must_use]
+    pub const fn owner(mut self, owner: ProgramOwner) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Finishes building the signature with the given flags.
+    #[must_use]
+    pub const fn with_flags(self, flags: ProgramFlags) -> CodeSignature {
+        CodeSignature::new(self.program_type, self.owner, flags)
+    }
 }
 
 unsafe extern "C" {
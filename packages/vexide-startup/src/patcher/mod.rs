@@ -1,5 +1,7 @@
 use varint_encoding::VarIntReader;
 use vexide_core::io::{Cursor, Read, Seek, SeekFrom};
+#[cfg(feature = "patcher-telemetry")]
+use vexide_core::{println, time::Instant};
 
 mod varint_encoding;
 
@@ -86,6 +88,13 @@ enum PatcherState {
 ///   of invalidating and cleaning instruction caches is described to further detail in
 ///   [ARM's documentation](https://developer.arm.com/documentation/den0013/latest/Caches/Invalidating-and-cleaning-cache-memory).
 ///
+/// # Telemetry
+///
+/// When the `patcher-telemetry` feature is enabled, a summary of the patch (the patch, base, and new
+/// binary sizes, along with how long stage 1 took) is printed over serial just before jumping to stage 2.
+/// The time reported does not include stage 2, since no Rust code can run while the program is being
+/// overwritten.
+///
 /// # Safety
 ///
 /// The caller must ensure that the patch loaded at 0x07A00000 has been built using the currently running
@@ -129,6 +138,9 @@ pub(crate) unsafe fn patch() {
         // Overwrite patch magic so we don't re-apply the patch next time.
         PATCH_MEMORY.write(0xB2DF);
 
+        #[cfg(feature = "patcher-telemetry")]
+        let start = Instant::now();
+
         // Slice representing our patch contents.
         let mut patch = core::slice::from_raw_parts(
             PATCH_MEMORY.offset(5).cast(),
@@ -203,6 +215,12 @@ pub(crate) unsafe fn patch() {
             new = &mut new[processed..];
         }
 
+        #[cfg(feature = "patcher-telemetry")]
+        println!(
+            "Applied {patch_len} byte patch to {base_binary_len} byte binary (new size: {new_binary_len} bytes) in {}ms",
+            start.elapsed().as_millis(),
+        );
+
         // Jump to the stage 2 overwriter routine to handle the rest.
         core::arch::asm!("b __patcher_overwrite", options(noreturn));
     }
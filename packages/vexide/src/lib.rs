@@ -12,7 +12,7 @@
 //!
 //! ## Project Structure
 //!
-//! The vexide runtime is split into 7 sub-crates. The one you're looking at right now re-exports each of these crates into a single package.
+//! The vexide runtime is split into 8 sub-crates. The one you're looking at right now re-exports each of these crates into a single package.
 //!
 //! - [`vexide-core`](https://docs.rs/vexide_core) provides low-level core functionality for programs, such as allocators, synchronization primitives, serial printing, I/O and timers.
 //! - [`vexide-devices`](https://docs.rs/vexide_devices) contains all device-related bindings for things like motors and sensors.
@@ -21,6 +21,7 @@
 //! - [`vexide-panic`](https://docs.rs/vexide_panic) contains our [panic handler](https://doc.rust-lang.org/nomicon/panic-handler.html).
 //! - [`vexide-graphics`](https://docs.rs/vexide_graphics) implements graphics drivers for some popular embedded Rust graphics libraries like [`slint`] and [`embedded-graphics`].
 //! - [`vexide-macro`](https://docs.rs/vexide_macro) contains the source code for the `#[vexide::main]` proc-macro.
+//! - [`vexide-math`](https://docs.rs/vexide_math) provides device-independent math utilities for robotics, such as odometry.
 //!
 //! [`slint`]: https://slint.rs/
 //! [`embedded-graphics`]: https://crates.io/crates/embedded-graphics
@@ -61,6 +62,9 @@ pub use vexide_macro as r#macro;
 #[cfg(feature = "macro")]
 pub use vexide_macro::main;
 #[doc(inline)]
+#[cfg(feature = "math")]
+pub use vexide_math as math;
+#[doc(inline)]
 #[cfg(feature = "panic")]
 pub use vexide_panic as panic;
 #[doc(inline)]
@@ -120,7 +124,7 @@ pub mod prelude {
             serial::SerialPort,
             vision::{
                 LedMode, VisionCode, VisionMode, VisionObject, VisionSensor, VisionSignature,
-                WhiteBalance,
+                VisionZeroPoint, WhiteBalance,
             },
             SmartDevice, SmartPort,
         },